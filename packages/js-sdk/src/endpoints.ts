@@ -0,0 +1,66 @@
+/**
+ * Endpoint Definitions
+ *
+ * Single source of truth for API routes. Module code never hard-codes a
+ * path: every operation references one entry here, so the JSON and
+ * multipart code paths of the same operation always hit the same route and
+ * new endpoints are added declaratively.
+ */
+
+export const Endpoints = {
+  sign: {
+    /** Prepare a document for review (no emails sent) — JSON or multipart */
+    prepareForReview: '/turbosign/single/prepare-for-review',
+    /** Prepare and immediately send signature emails — JSON or multipart */
+    prepareForSigning: '/turbosign/single/prepare-for-signing',
+    /** Send a document for receipt acknowledgment (no signature fields) — JSON or multipart */
+    sendForAcknowledgment: '/turbosign/single/send-for-acknowledgment',
+    /** Saved field layouts for a template */
+    fieldLayouts: (templateId: string) => `/turbosign/templates/${templateId}/field-layouts`,
+    /** Sender addresses verified for this org */
+    verifiedSenders: '/turbosign/verified-senders',
+    /** Request (or re-check) verification for a sender address */
+    verifySender: '/turbosign/verified-senders/verify',
+    /** Org-defined custom document property schema */
+    customFieldDefinitions: '/turbosign/custom-field-definitions',
+    /** Paginated document list */
+    documents: '/turbosign/documents',
+    /** Documents nearing their signing deadline */
+    documentsExpiring: '/turbosign/documents/expiring',
+    archive: (documentId: string) => `/turbosign/documents/${documentId}/archive`,
+    unarchive: (documentId: string) => `/turbosign/documents/${documentId}/unarchive`,
+    clone: (documentId: string) => `/turbosign/documents/${documentId}/clone`,
+    void: (documentId: string) => `/turbosign/documents/${documentId}/void`,
+    resendEmail: (documentId: string) => `/turbosign/documents/${documentId}/resend-email`,
+    auditTrail: (documentId: string) => `/turbosign/documents/${documentId}/audit-trail`,
+    emailStatus: (documentId: string) => `/turbosign/documents/${documentId}/email-status`,
+    originalRequest: (documentId: string) => `/turbosign/documents/${documentId}/original-request`,
+    download: (documentId: string) => `/turbosign/documents/${documentId}/download`,
+    status: (documentId: string) => `/turbosign/documents/${documentId}/status`,
+    /** Data submitted into a document's fields (form and signature modes alike) */
+    fieldValues: (documentId: string) => `/turbosign/documents/${documentId}/field-values`,
+    /** Estimate envelope/page counts and credit consumption before a batch */
+    estimate: '/turbosign/estimate',
+    /** Server-side bulk jobs — list and monitor */
+    bulkJobs: '/turbosign/bulk-jobs',
+    bulkJob: (jobId: string) => `/turbosign/bulk-jobs/${jobId}`,
+    cancelBulkJob: (jobId: string) => `/turbosign/bulk-jobs/${jobId}/cancel`,
+    /** Sandbox-only: drive a recipient through the signing flow in tests */
+    simulateRecipientAction: (documentId: string) => `/turbosign/sandbox/documents/${documentId}/simulate`,
+  },
+  deliverable: {
+    /** Deliverable collection — list and generate */
+    root: '/v1/deliverable',
+    byId: (id: string) => `/v1/deliverable/${id}`,
+    shareLink: (id: string) => `/v1/deliverable/${id}/share-link`,
+    sourceFile: (id: string) => `/v1/deliverable/file/${id}`,
+    /** Advisory lock against concurrent regeneration */
+    lock: (id: string) => `/v1/deliverable/${id}/lock`,
+    unlock: (id: string) => `/v1/deliverable/${id}/unlock`,
+    /** Stop a queued or running generation job */
+    cancelGeneration: (id: string) => `/v1/deliverable/${id}/cancel-generation`,
+    /** TurboSign documents generated from a deliverable (reverse lineage lookup) */
+    signatures: (id: string) => `/v1/deliverable/${id}/signatures`,
+    pdfFile: (id: string) => `/v1/deliverable/file/pdf/${id}`,
+  },
+} as const;
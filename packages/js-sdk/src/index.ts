@@ -3,17 +3,60 @@
  */
 
 // Export modules
-export { TurboSign } from './modules/sign';
+export { TurboSign, TurboSignClient } from './modules/sign';
 export { TurboPartner } from './modules/partner';
-export { Deliverable } from './modules/deliverable';
+export { Deliverable, DeliverableClient } from './modules/deliverable';
+export { Workflow, WorkflowRunner, loadWorkflowSpec } from './modules/workflow';
 
 // Export types
 export * from './types/sign';
 export * from './types/partner';
 export * from './types/deliverable';
+export * from './types/workflow';
 
 // Export errors
 export * from './utils/errors';
 
-// Export HTTP client config types
-export type { HttpClientConfig, PartnerClientConfig } from './http';
+// Export field helpers
+export * from './utils/fields';
+export { TemplateAnchor } from './utils/anchors';
+
+// Export variable builders
+export * from './utils/variables';
+
+// Export CSV helpers used by bulk send
+export { parseCsv, parseCsvRecords } from './utils/csv';
+
+// Export offline PDF verification
+export * from './utils/pdf-verify';
+
+// Export endpoint definitions
+export { Endpoints } from './endpoints';
+
+// Export lifecycle state machines
+export * from './utils/lifecycle';
+
+// Export circuit breaker config
+export type { CircuitBreakerOptions } from './utils/circuit';
+
+// Export tracing interfaces (OpenTelemetry-compatible)
+export type { TraceAttributeValue, TraceSpan, Tracer } from './utils/tracing';
+
+// Export metrics sink interface and label helpers
+export type { MetricsSink, RequestMetric, StatusClass } from './utils/metrics';
+export { normalizeEndpoint, toStatusClass } from './utils/metrics';
+
+// Export policy normalization helpers
+export { resolveBulkSendOptions, resolveWatchOptions, validateRetryPolicy } from './utils/policies';
+
+// Export pacing utility for bulk/resend loops
+export { Pacer } from './utils/pacer';
+export type { PacerOptions } from './utils/pacer';
+
+// Export layered config loader
+export { loadConfig } from './utils/config';
+export type { ConfigFile } from './utils/config';
+
+// Export HTTP client config types and env diagnostics
+export type { ApiResponse, HttpClientConfig, PartnerClientConfig, EnvConfigIssue, EnvConfigReport, Middleware, MiddlewareRequest, NextMiddleware, RequestOptions, ResponseEvent, TokenProvider } from './http';
+export { checkEnvConfig } from './http';
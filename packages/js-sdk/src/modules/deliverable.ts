@@ -12,10 +12,12 @@
  * - downloadPDF
  */
 
-import { HttpClient } from '../http';
+import { DownloadToFileResult, HttpClient, RequestOptions } from '../http';
 import {
   DeliverableConfig,
+  CancelGenerationResponse,
   CreateDeliverableRequest,
+  LockDeliverableResponse,
   CreateDeliverableResponse,
   UpdateDeliverableRequest,
   UpdateDeliverableResponse,
@@ -24,28 +26,31 @@ import {
   GetDeliverableOptions,
   DeliverableListResponse,
   DeliverableRecord,
+  DeliverableSignaturesResponse,
+  IterateDeliverablesOptions,
+  ShareLinkOptions,
+  ShareLinkResponse,
 } from '../types/deliverable';
+import { Endpoints } from '../endpoints';
+import { decodeResumeToken, encodeResumeToken } from '../utils/resume';
+import { TurboDocxError } from '../utils/errors';
 
-export class Deliverable {
-  private static client: HttpClient;
+/**
+ * Instance client for Deliverable operations
+ *
+ * Owns its own HttpClient, so one process can generate documents for
+ * several orgs or environments at the same time. The Deliverable static
+ * facade below wraps a single shared instance for the common one-org case.
+ */
+export class DeliverableClient {
+  private client: HttpClient;
 
   /**
-   * Configure the Deliverable module with API credentials
-   *
-   * @param config - Configuration object
-   * @param config.apiKey - TurboDocx API key (required)
-   * @param config.orgId - Organization ID (required)
-   * @param config.baseUrl - API base URL (optional, defaults to https://api.turbodocx.com)
+   * Create a client with its own credentials
    *
-   * @example
-   * ```typescript
-   * Deliverable.configure({
-   *   apiKey: process.env.TURBODOCX_API_KEY,
-   *   orgId: process.env.TURBODOCX_ORG_ID,
-   * });
-   * ```
+   * @param config - Same options as Deliverable.configure
    */
-  static configure(config: DeliverableConfig): void {
+  constructor(config: DeliverableConfig) {
     this.client = new HttpClient({
       apiKey: config.apiKey,
       accessToken: config.accessToken,
@@ -56,17 +61,33 @@ export class Deliverable {
   }
 
   /**
-   * Get the HTTP client instance, throwing if not configured
+   * Get the HTTP client instance
    */
-  private static getClient(): HttpClient {
-    if (!this.client) {
-      throw new Error(
-        'Deliverable not configured. Call Deliverable.configure({ apiKey, orgId }) first.'
-      );
-    }
+  private getClient(): HttpClient {
     return this.client;
   }
 
+  /**
+   * Annotate failures from an HTTP call with the operation name, so errors
+   * in logs read 'Deliverable.generateDeliverable: ...' instead of a bare
+   * message
+   */
+  private op<T>(operation: string, work: Promise<T>): Promise<T> {
+    return work.catch((error) => {
+      throw TurboDocxError.inOperation(operation, error);
+    });
+  }
+
+  /**
+   * Pre-establish the connection to the API ahead of the first real request
+   *
+   * Useful in serverless deployments where the first generate call otherwise
+   * pays DNS/TLS setup latency. Best-effort: failures are swallowed.
+   */
+  async warmUp(): Promise<void> {
+    return this.getClient().warmUp();
+  }
+
   // ============================================
   // DELIVERABLE CRUD
   // ============================================
@@ -88,7 +109,7 @@ export class Deliverable {
    * console.log(`Found ${totalRecords} deliverables`);
    * ```
    */
-  static async listDeliverables(options?: ListDeliverablesOptions): Promise<DeliverableListResponse> {
+  async listDeliverables(options?: ListDeliverablesOptions): Promise<DeliverableListResponse> {
     const client = this.getClient();
     const params: Record<string, any> = {};
 
@@ -96,10 +117,77 @@ export class Deliverable {
       if (options.limit !== undefined) params.limit = options.limit;
       if (options.offset !== undefined) params.offset = options.offset;
       if (options.query !== undefined) params.query = options.query;
+      if (options.tag !== undefined) params.tag = options.tag;
       if (options.showTags !== undefined) params.showTags = options.showTags;
     }
 
-    return client.get<DeliverableListResponse>('/v1/deliverable', params);
+    return this.op('Deliverable.listDeliverables', client.get<DeliverableListResponse>(Endpoints.deliverable.root, params));
+  }
+
+  /**
+   * List deliverables carrying a specific tag
+   *
+   * Convenience over listDeliverables for reporting flows that slice
+   * deliverables by tag (set via the tags option at generation).
+   *
+   * @param tag - Tag to filter by
+   * @param options - Additional pagination and query options
+   * @returns Paginated list of matching deliverables
+   *
+   * @example
+   * ```typescript
+   * const { results } = await Deliverable.listByTag('renewal', { limit: 50 });
+   * ```
+   */
+  async listByTag(tag: string, options?: Omit<ListDeliverablesOptions, 'tag'>): Promise<DeliverableListResponse> {
+    return this.listDeliverables({ ...options, tag });
+  }
+
+  /**
+   * Iterate all deliverables page by page
+   *
+   * Async generator that fetches one page at a time, so memory stays flat
+   * while walking an entire org's deliverables - use this instead of
+   * listDeliverables for exports over tens of thousands of records.
+   *
+   * Pass onResumeToken to receive a persistable token after each record;
+   * a later run can hand it back as resumeToken to continue from the exact
+   * record the previous run stopped at.
+   *
+   * @param options - Page size, filters, and resume behavior
+   * @yields One deliverable at a time, in list order
+   *
+   * @example
+   * ```typescript
+   * for await (const deliverable of Deliverable.iterateDeliverables({ query: 'contract' })) {
+   *   console.log(deliverable.name);
+   * }
+   * ```
+   */
+  async *iterateDeliverables(options?: IterateDeliverablesOptions): AsyncGenerator<DeliverableRecord, void, undefined> {
+    const pageSize = options?.pageSize ?? 100;
+    let offset = options?.resumeToken !== undefined
+      ? decodeResumeToken(options.resumeToken).offset
+      : options?.offset ?? 0;
+
+    while (true) {
+      const page = await this.listDeliverables({
+        limit: pageSize,
+        offset,
+        query: options?.query,
+        showTags: options?.showTags,
+      });
+
+      for (const record of page.results) {
+        yield record;
+        offset++;
+        options?.onResumeToken?.(encodeResumeToken({ offset }));
+      }
+
+      if (page.results.length < pageSize || offset >= page.totalRecords) {
+        return;
+      }
+    }
   }
 
   /**
@@ -122,9 +210,103 @@ export class Deliverable {
    * console.log(`Generated: ${result.results.deliverable.id}`);
    * ```
    */
-  static async generateDeliverable(request: CreateDeliverableRequest): Promise<CreateDeliverableResponse> {
+  async generateDeliverable(request: CreateDeliverableRequest): Promise<CreateDeliverableResponse> {
+    const client = this.getClient();
+    return this.op('Deliverable.generateDeliverable', client.post<CreateDeliverableResponse>(Endpoints.deliverable.root, request));
+  }
+
+  /**
+   * Take the regeneration lock on a deliverable
+   *
+   * Two workers processing the same order can otherwise clobber each
+   * other's regenerated output. The lock is exclusive: a second lock
+   * attempt fails, with the current holder named in the error, until
+   * unlock is called. Locks guard regeneration and deletion; reads are
+   * unaffected.
+   *
+   * @param deliverableId - ID of the deliverable to lock
+   * @returns Lock confirmation with holder and timestamp
+   *
+   * @example
+   * ```typescript
+   * await Deliverable.lock(deliverableId);
+   * try {
+   *   await Deliverable.generateDeliverable({ ... });
+   * } finally {
+   *   await Deliverable.unlock(deliverableId);
+   * }
+   * ```
+   */
+  async lock(deliverableId: string): Promise<LockDeliverableResponse> {
+    const client = this.getClient();
+    return this.op('Deliverable.lock', client.post<LockDeliverableResponse>(
+      Endpoints.deliverable.lock(deliverableId)
+    ));
+  }
+
+  /**
+   * Release the regeneration lock on a deliverable
+   *
+   * @param deliverableId - ID of the deliverable to unlock
+   * @returns Confirmation that the lock was released
+   */
+  async unlock(deliverableId: string): Promise<LockDeliverableResponse> {
     const client = this.getClient();
-    return client.post<CreateDeliverableResponse>('/v1/deliverable', request);
+    return this.op('Deliverable.unlock', client.post<LockDeliverableResponse>(
+      Endpoints.deliverable.unlock(deliverableId)
+    ));
+  }
+
+  /**
+   * Stop a queued or running generation job
+   *
+   * For async generations kicked off by mistake — wrong variables on a
+   * large batch — before they burn through the queue. Cancellation races
+   * the job: the response says whether the job was stopped or had already
+   * finished, so callers can decide between cleaning up and moving on.
+   *
+   * @param deliverableId - ID of the deliverable being generated
+   * @returns Whether the job was cancelled, or why it could not be
+   *
+   * @example
+   * ```typescript
+   * const result = await Deliverable.cancelGeneration(deliverableId);
+   * if (!result.cancelled) {
+   *   console.log(`Too late: ${result.outcome}`);
+   * }
+   * ```
+   */
+  async cancelGeneration(deliverableId: string): Promise<CancelGenerationResponse> {
+    const client = this.getClient();
+    return this.op('Deliverable.cancelGeneration', client.post<CancelGenerationResponse>(
+      Endpoints.deliverable.cancelGeneration(deliverableId)
+    ));
+  }
+
+  /**
+   * Find a deliverable by its caller-supplied external ID
+   *
+   * Pairs with the externalId option on generateDeliverable: retry logic can
+   * look up whether a job already produced a deliverable before generating.
+   *
+   * @param externalId - The external ID set at generation time
+   * @returns The matching deliverable, or null if none exists
+   *
+   * @example
+   * ```typescript
+   * const existing = await Deliverable.findByExternalId('order-9182');
+   * if (!existing) {
+   *   await Deliverable.generateDeliverable({ ..., externalId: 'order-9182' });
+   * }
+   * ```
+   */
+  async findByExternalId(externalId: string): Promise<DeliverableRecord | null> {
+    const client = this.getClient();
+    const response = await this.op('Deliverable.findByExternalId', client.get<DeliverableListResponse>(Endpoints.deliverable.root, {
+      externalId,
+      limit: 1,
+    }));
+    return response.results[0] ?? null;
   }
 
   /**
@@ -143,15 +325,38 @@ export class Deliverable {
    * console.log(deliverable.name, deliverable.variables);
    * ```
    */
-  static async getDeliverableDetails(id: string, options?: GetDeliverableOptions): Promise<DeliverableRecord> {
+  async getDeliverableDetails(id: string, options?: GetDeliverableOptions): Promise<DeliverableRecord> {
     const client = this.getClient();
     const params: Record<string, any> = {};
     if (options?.showTags !== undefined) params.showTags = options.showTags;
 
-    const response = await client.get<{ results: DeliverableRecord }>(`/v1/deliverable/${id}`, params);
+    const response = await this.op('Deliverable.getDeliverableDetails', client.get<{ results: DeliverableRecord }>(Endpoints.deliverable.byId(id), params));
     return response.results;
   }
 
+  /**
+   * List TurboSign documents generated from a deliverable
+   *
+   * Reverse lineage lookup: each signing document carries `generatedFrom`
+   * metadata pointing back at its template/deliverable, and this returns
+   * all signature requests created from the given deliverable.
+   *
+   * @param id - Deliverable UUID
+   * @returns TurboSign documents generated from the deliverable
+   *
+   * @example
+   * ```typescript
+   * const { results } = await Deliverable.listSignaturesForDeliverable('deliverable-uuid');
+   * const pending = results.filter((s) => s.status !== 'completed');
+   * ```
+   */
+  async listSignaturesForDeliverable(id: string): Promise<DeliverableSignaturesResponse> {
+    const client = this.getClient();
+    return this.op('Deliverable.listSignaturesForDeliverable', client.get<DeliverableSignaturesResponse>(
+      Endpoints.deliverable.signatures(id)
+    ));
+  }
+
   /**
    * Update a deliverable's name, description, or tags
    *
@@ -173,9 +378,9 @@ export class Deliverable {
    * );
    * ```
    */
-  static async updateDeliverableInfo(id: string, request: UpdateDeliverableRequest): Promise<UpdateDeliverableResponse> {
+  async updateDeliverableInfo(id: string, request: UpdateDeliverableRequest): Promise<UpdateDeliverableResponse> {
     const client = this.getClient();
-    return client.patch<UpdateDeliverableResponse>(`/v1/deliverable/${id}`, request);
+    return this.op('Deliverable.updateDeliverableInfo', client.patch<UpdateDeliverableResponse>(Endpoints.deliverable.byId(id), request));
   }
 
   /**
@@ -191,9 +396,39 @@ export class Deliverable {
    * console.log(result.message); // "Deliverable deleted successfully"
    * ```
    */
-  static async deleteDeliverable(id: string): Promise<DeleteDeliverableResponse> {
+  async deleteDeliverable(id: string): Promise<DeleteDeliverableResponse> {
     const client = this.getClient();
-    return client.delete<DeleteDeliverableResponse>(`/v1/deliverable/${id}`);
+    return this.op('Deliverable.deleteDeliverable', client.delete<DeleteDeliverableResponse>(Endpoints.deliverable.byId(id)));
+  }
+
+  /**
+   * Create a shareable link for a deliverable
+   *
+   * Returns a hosted URL that can be sent to customers directly, instead of
+   * exposing raw presigned S3 URLs. Links can expire, allow or forbid
+   * download, and be password protected.
+   *
+   * @param deliverableId - Deliverable UUID
+   * @param options - Expiry, download permission, and password settings
+   * @returns The shareable URL and its settings
+   *
+   * @example
+   * ```typescript
+   * const link = await Deliverable.createShareLink('deliverable-uuid', {
+   *   expiresIn: 7 * 24 * 60 * 60, // one week
+   *   allowDownload: true,
+   * });
+   * console.log(link.url);
+   * ```
+   */
+  async createShareLink(deliverableId: string, options?: ShareLinkOptions): Promise<ShareLinkResponse> {
+    const client = this.getClient();
+    const body: Record<string, any> = {};
+    if (options?.expiresIn !== undefined) body.expiresIn = options.expiresIn;
+    if (options?.allowDownload !== undefined) body.allowDownload = options.allowDownload;
+    if (options?.password !== undefined) body.password = options.password;
+
+    return this.op('Deliverable.createShareLink', client.post<ShareLinkResponse>(Endpoints.deliverable.shareLink(deliverableId), body));
   }
 
   // ============================================
@@ -206,6 +441,7 @@ export class Deliverable {
    * Requires the `hasFileDownload` feature to be enabled on your organization's license.
    *
    * @param deliverableId - Deliverable UUID
+   * @param options - Per-call request options (signal, deadline, timeout)
    * @returns Raw file content as ArrayBuffer
    *
    * @example
@@ -220,15 +456,16 @@ export class Deliverable {
    * const url = URL.createObjectURL(blob);
    * ```
    */
-  static async downloadSourceFile(deliverableId: string): Promise<ArrayBuffer> {
+  async downloadSourceFile(deliverableId: string, options?: RequestOptions): Promise<ArrayBuffer> {
     const client = this.getClient();
-    return client.getRaw(`/v1/deliverable/file/${deliverableId}`);
+    return this.op('Deliverable.downloadSourceFile', client.getRaw(Endpoints.deliverable.sourceFile(deliverableId), options));
   }
 
   /**
    * Download the PDF version of a deliverable
    *
    * @param deliverableId - Deliverable UUID
+   * @param options - Per-call request options (signal, deadline, timeout)
    * @returns Raw PDF content as ArrayBuffer
    *
    * @example
@@ -242,9 +479,194 @@ export class Deliverable {
    * const blob = new Blob([buffer], { type: 'application/pdf' });
    * ```
    */
-  static async downloadPDF(deliverableId: string): Promise<ArrayBuffer> {
+  async downloadPDF(deliverableId: string, options?: RequestOptions): Promise<ArrayBuffer> {
+    const client = this.getClient();
+    return this.op('Deliverable.downloadPDF', client.getRaw(Endpoints.deliverable.pdfFile(deliverableId), options));
+  }
+
+  /**
+   * Download the original source file straight to a file on disk
+   *
+   * Streams the file to the given path instead of buffering it in memory.
+   *
+   * @param deliverableId - Deliverable UUID
+   * @param filePath - Local path to write the file to
+   * @param options - Per-call request options (signal, deadline, timeout)
+   * @returns The file path and number of bytes written
+   */
+  async downloadSourceFileToFile(deliverableId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
     const client = this.getClient();
-    return client.getRaw(`/v1/deliverable/file/pdf/${deliverableId}`);
+    return this.op('Deliverable.downloadSourceFileToFile', client.getRawToFile(Endpoints.deliverable.sourceFile(deliverableId), filePath, options));
+  }
+
+  /**
+   * Download the PDF version straight to a file on disk
+   *
+   * Streams the PDF to the given path instead of buffering it in memory,
+   * so small containers can handle occasional huge deliverables.
+   *
+   * @param deliverableId - Deliverable UUID
+   * @param filePath - Local path to write the PDF to
+   * @param options - Per-call request options (signal, deadline, timeout)
+   * @returns The file path and number of bytes written
+   *
+   * @example
+   * ```typescript
+   * await Deliverable.downloadPDFToFile('deliverable-uuid', '/tmp/contract.pdf');
+   * ```
+   */
+  async downloadPDFToFile(deliverableId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
+    const client = this.getClient();
+    return this.op('Deliverable.downloadPDFToFile', client.getRawToFile(Endpoints.deliverable.pdfFile(deliverableId), filePath, options));
+  }
+
+  /**
+   * Drain this client for clean termination, e.g. from a SIGTERM handler.
+   * New requests are rejected and the promise resolves once in-flight
+   * requests settle or graceMs elapses. See {@link HttpClient.shutdown}.
+   *
+   * @param graceMs - How long to wait for in-flight requests (default 10000)
+   */
+  shutdown(graceMs?: number): Promise<void> {
+    return this.getClient().shutdown(graceMs);
+  }
+
+}
+
+/**
+ * Deliverable Module - Document generation and management (static facade)
+ *
+ * Thin wrapper around a single shared DeliverableClient. Configure once,
+ * then call static methods. Construct DeliverableClient directly to talk to
+ * several orgs or environments from the same process.
+ */
+export class Deliverable {
+  private static client: DeliverableClient;
+
+  /**
+   * Configure the Deliverable module with API credentials
+   *
+   * @param config - Configuration object
+   * @param config.apiKey - TurboDocx API key (required)
+   * @param config.orgId - Organization ID (required)
+   * @param config.baseUrl - API base URL (optional, defaults to https://api.turbodocx.com)
+   *
+   * @example
+   * ```typescript
+   * Deliverable.configure({
+   *   apiKey: process.env.TURBODOCX_API_KEY,
+   *   orgId: process.env.TURBODOCX_ORG_ID,
+   * });
+   * ```
+   */
+  static configure(config: DeliverableConfig): void {
+    this.client = new DeliverableClient(config);
+  }
+
+  /**
+   * Get the shared client instance, throwing if not configured
+   */
+  private static getInstance(): DeliverableClient {
+    if (!this.client) {
+      throw new Error(
+        'Deliverable not configured. Call Deliverable.configure({ apiKey, orgId }) first.'
+      );
+    }
+    return this.client;
+  }
+
+  /** See {@link DeliverableClient.warmUp} */
+  static warmUp(): Promise<void> {
+    return this.getInstance().warmUp();
+  }
+
+  /** See {@link DeliverableClient.listDeliverables} */
+  static listDeliverables(options?: ListDeliverablesOptions): Promise<DeliverableListResponse> {
+    return this.getInstance().listDeliverables(options);
+  }
+
+  /** See {@link DeliverableClient.listByTag} */
+  static listByTag(tag: string, options?: Omit<ListDeliverablesOptions, 'tag'>): Promise<DeliverableListResponse> {
+    return this.getInstance().listByTag(tag, options);
+  }
+
+  /** See {@link DeliverableClient.iterateDeliverables} */
+  static iterateDeliverables(options?: IterateDeliverablesOptions): AsyncGenerator<DeliverableRecord, void, undefined> {
+    return this.getInstance().iterateDeliverables(options);
+  }
+
+  /** See {@link DeliverableClient.generateDeliverable} */
+  static generateDeliverable(request: CreateDeliverableRequest): Promise<CreateDeliverableResponse> {
+    return this.getInstance().generateDeliverable(request);
   }
 
+  /** See {@link DeliverableClient.lock} */
+  static lock(deliverableId: string): Promise<LockDeliverableResponse> {
+    return this.getInstance().lock(deliverableId);
+  }
+
+  /** See {@link DeliverableClient.unlock} */
+  static unlock(deliverableId: string): Promise<LockDeliverableResponse> {
+    return this.getInstance().unlock(deliverableId);
+  }
+
+  /** See {@link DeliverableClient.cancelGeneration} */
+  static cancelGeneration(deliverableId: string): Promise<CancelGenerationResponse> {
+    return this.getInstance().cancelGeneration(deliverableId);
+  }
+
+  /** See {@link DeliverableClient.findByExternalId} */
+  static findByExternalId(externalId: string): Promise<DeliverableRecord | null> {
+    return this.getInstance().findByExternalId(externalId);
+  }
+
+  /** See {@link DeliverableClient.getDeliverableDetails} */
+  static getDeliverableDetails(id: string, options?: GetDeliverableOptions): Promise<DeliverableRecord> {
+    return this.getInstance().getDeliverableDetails(id, options);
+  }
+
+  /** See {@link DeliverableClient.listSignaturesForDeliverable} */
+  static listSignaturesForDeliverable(id: string): Promise<DeliverableSignaturesResponse> {
+    return this.getInstance().listSignaturesForDeliverable(id);
+  }
+
+  /** See {@link DeliverableClient.updateDeliverableInfo} */
+  static updateDeliverableInfo(id: string, request: UpdateDeliverableRequest): Promise<UpdateDeliverableResponse> {
+    return this.getInstance().updateDeliverableInfo(id, request);
+  }
+
+  /** See {@link DeliverableClient.deleteDeliverable} */
+  static deleteDeliverable(id: string): Promise<DeleteDeliverableResponse> {
+    return this.getInstance().deleteDeliverable(id);
+  }
+
+  /** See {@link DeliverableClient.createShareLink} */
+  static createShareLink(deliverableId: string, options?: ShareLinkOptions): Promise<ShareLinkResponse> {
+    return this.getInstance().createShareLink(deliverableId, options);
+  }
+
+  /** See {@link DeliverableClient.downloadSourceFile} */
+  static downloadSourceFile(deliverableId: string, options?: RequestOptions): Promise<ArrayBuffer> {
+    return this.getInstance().downloadSourceFile(deliverableId, options);
+  }
+
+  /** See {@link DeliverableClient.downloadPDF} */
+  static downloadPDF(deliverableId: string, options?: RequestOptions): Promise<ArrayBuffer> {
+    return this.getInstance().downloadPDF(deliverableId, options);
+  }
+
+  /** See {@link DeliverableClient.downloadSourceFileToFile} */
+  static downloadSourceFileToFile(deliverableId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
+    return this.getInstance().downloadSourceFileToFile(deliverableId, filePath, options);
+  }
+
+  /** See {@link DeliverableClient.downloadPDFToFile} */
+  static downloadPDFToFile(deliverableId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
+    return this.getInstance().downloadPDFToFile(deliverableId, filePath, options);
+  }
+
+  /** See {@link DeliverableClient.shutdown} */
+  static shutdown(graceMs?: number): Promise<void> {
+    return this.getInstance().shutdown(graceMs);
+  }
 }
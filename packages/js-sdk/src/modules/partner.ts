@@ -3,6 +3,7 @@
  */
 
 import { HttpClient, PartnerClientConfig } from '../http';
+import { envVar } from '../utils/runtime';
 import {
   CreateOrganizationRequest,
   ListOrganizationsRequest,
@@ -94,8 +95,8 @@ export class TurboPartner {
    */
   private static getClient(): HttpClient {
     if (!this.client) {
-      const partnerApiKey = process.env.TURBODOCX_PARTNER_API_KEY;
-      const partnerId = process.env.TURBODOCX_PARTNER_ID;
+      const partnerApiKey = envVar('TURBODOCX_PARTNER_API_KEY');
+      const partnerId = envVar('TURBODOCX_PARTNER_ID');
       if (!partnerApiKey || !partnerId) {
         throw new Error('TurboPartner must be configured before use. Call TurboPartner.configure() or set TURBODOCX_PARTNER_API_KEY and TURBODOCX_PARTNER_ID environment variables.');
       }
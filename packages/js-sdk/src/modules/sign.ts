@@ -2,54 +2,231 @@
  * TurboSign Module - Digital signature operations
  */
 
-import { HttpClient, HttpClientConfig } from '../http';
+import { ApiResponse, DownloadToFileResult, HttpClient, HttpClientConfig, RequestOptions, streamResponseToFile, verifyDownloadIntegrity } from '../http';
 import {
+  CloneDocumentOptions,
+  CloneDocumentResponse,
+  ArchiveDocumentResponse,
+  ListDocumentsOptions,
+  DocumentListResponse,
+  DocumentListItem,
+  IterateDocumentsOptions,
+  ExpiringDocumentsResponse,
+  EmailStatusResponse,
+  OriginalRequestResponse,
+  Field,
+  SaveFieldLayoutResponse,
+  VoidDocumentOptions,
   VoidDocumentResponse,
   ResendEmailResponse,
+  ListVerifiedSendersResponse,
+  VerifySenderResponse,
+  RecipientAction,
+  SimulateRecipientActionResponse,
+  CostEstimateRequest,
+  CostEstimateResponse,
+  CustomFieldDefinition,
+  CustomFieldDefinitionsResponse,
+  CustomFieldValues,
+  QuotaCheck,
+  QuotaStatus,
   AuditTrailResponse,
   DocumentStatusResponse,
+  DocumentStatusChange,
+  WatchOptions,
   CreateSignatureReviewLinkRequest,
   CreateSignatureReviewLinkResponse,
   SendSignatureRequest,
   SendSignatureResponse,
+  SendForAcknowledgmentRequest,
+  SendForAcknowledgmentResponse,
+  FieldValuesResponse,
+  BulkSendFromCsvOptions,
+  BulkRowResult,
+  BulkSendReport,
+  BulkJob,
+  BulkJobListResponse,
+  ListBulkJobsOptions,
 } from '../types/sign';
+import { convertFieldUnits, normalizeCoordinates, toPixels, validateCustomFieldValues, validateTabOrder, validateTimeZone } from '../utils/fields';
+import { decodeResumeToken, encodeResumeToken } from '../utils/resume';
+import { Endpoints } from '../endpoints';
+import { parseCsvRecords } from '../utils/csv';
+import { resolveBulkSendOptions, resolveWatchOptions } from '../utils/policies';
+import { InvalidStateError, QuotaExceededError, QuotaLowError, RateLimitError, TurboDocxError, ValidationError } from '../utils/errors';
+import { canDocumentTransition, isTerminalDocumentStatus } from '../utils/lifecycle';
 
-export class TurboSign {
-  private static client: HttpClient;
+/** Human-readable message from any thrown value */
+const errorMessage = (error: unknown): string =>
+  error instanceof Error ? error.message : String(error);
+
+/**
+ * Instance client for TurboSign operations
+ *
+ * Owns its own HttpClient, so one process can talk to several orgs or
+ * environments at the same time. The TurboSign static facade below wraps a
+ * single shared instance for the common one-org case.
+ *
+ * @example
+ * ```typescript
+ * const usClient = new TurboSignClient({ apiKey, orgId: usOrgId, senderEmail });
+ * const euClient = new TurboSignClient({ apiKey, orgId: euOrgId, senderEmail });
+ * await usClient.getStatus('doc-1');
+ * ```
+ */
+export class TurboSignClient {
+  private client: HttpClient;
+  /** Org custom property schema, fetched lazily and cached for the client's lifetime */
+  private customFieldDefinitions?: CustomFieldDefinition[];
 
   /**
-   * Configure the TurboSign module with API credentials
+   * Create a client with its own credentials
    *
-   * @param config - Configuration object
-   * @param config.apiKey - TurboDocx API key (required)
-   * @param config.orgId - Organization ID (required)
-   * @param config.senderEmail - Reply-to email address for signature requests (required). This email will be used as the reply-to address when sending signature request emails. Without it, emails will default to "API Service User via TurboSign".
-   * @param config.senderName - Sender name for signature requests (optional but strongly recommended). This name will appear in signature request emails. Without this, the sender will appear as "API Service User".
-   * @param config.baseUrl - API base URL (optional, defaults to https://api.turbodocx.com)
+   * @param config - Same options as TurboSign.configure; falls back to
+   *   TURBODOCX_* environment variables for omitted fields
+   */
+  constructor(config: HttpClientConfig = {}) {
+    this.client = new HttpClient(config);
+  }
+
+  /**
+   * Get the HTTP client instance
+   */
+  private getClient(): HttpClient {
+    return this.client;
+  }
+
+  /**
+   * Annotate failures from an HTTP call with the operation name, so errors
+   * in logs read 'TurboSign.getAuditTrail: ...' instead of a bare message
+   */
+  private op<T>(operation: string, work: Promise<T>): Promise<T> {
+    return work.catch((error) => {
+      throw TurboDocxError.inOperation(operation, error);
+    });
+  }
+
+  /**
+   * Pre-establish the connection to the API ahead of the first real request
+   *
+   * Useful in serverless deployments where the first call otherwise pays
+   * DNS/TLS setup latency. Best-effort: failures are swallowed.
    *
    * @example
    * ```typescript
-   * TurboSign.configure({
-   *   apiKey: process.env.TURBODOCX_API_KEY,
-   *   orgId: process.env.TURBODOCX_ORG_ID,
-   *   senderEmail: 'support@yourcompany.com',
-   *   senderName: 'Your Company Name'  // Strongly recommended
-   * });
+   * TurboSign.configure({ apiKey, orgId, senderEmail });
+   * await TurboSign.warmUp(); // during cold start, before handling traffic
    * ```
    */
-  static configure(config: HttpClientConfig): void {
-    this.client = new HttpClient(config);
+  async warmUp(): Promise<void> {
+    return this.getClient().warmUp();
   }
 
   /**
-   * Get the HTTP client instance, initializing if necessary
+   * Build the shared form payload for review-link and send operations
+   *
+   * Validates the request, applies unit/coordinate conversion, and
+   * serializes each JSON-valued field (recipients, fields, ccEmails,
+   * redirectUrls) to a string exactly once. This is the single place
+   * payload serialization happens for both the multipart and JSON paths.
    */
-  private static getClient(): HttpClient {
-    if (!this.client) {
-      // Auto-initialize with environment variables if not configured
-      this.client = new HttpClient();
+  private buildSignaturePayload(
+    request: CreateSignatureReviewLinkRequest | SendSignatureRequest,
+    client: HttpClient
+  ): Record<string, any> {
+    // Either an inline field array or a saved layout reference is required
+    if (!request.fields && !request.fieldLayoutId) {
+      throw new ValidationError('Either fields or fieldLayoutId must be provided.');
     }
-    return this.client;
+
+    // Validate tab order before uploading anything
+    validateTabOrder(request.fields ?? []);
+
+    // Form-mode documents collect typed data only — a signature field on one
+    // is a mistake that would otherwise block completion server-side
+    if (request.mode === 'form') {
+      const signatureField = request.fields?.find((f) => f.type === 'signature' || f.type === 'initial');
+      if (signatureField) {
+        throw new ValidationError(
+          `Form mode documents cannot contain '${signatureField.type}' fields. Use mode 'signature' for documents that need a signing ceremony.`
+        );
+      }
+    }
+
+    // Validate time zones up front — a typo'd zone would otherwise surface
+    // server-side after the document is created
+    if (request.timeZone) {
+      validateTimeZone(request.timeZone);
+    }
+    for (const recipient of request.recipients) {
+      if (recipient.timeZone) {
+        validateTimeZone(recipient.timeZone, `timeZone for recipient ${recipient.email}`);
+      }
+    }
+
+    // Get sender config from client
+    const senderConfig = client.getSenderConfig();
+
+    // Convert units and coordinates to what the API expects (pixels, top-left origin)
+    const pageHeight = request.pageHeight !== undefined && request.unit
+      ? toPixels(request.pageHeight, request.unit)
+      : request.pageHeight;
+    const fields = request.fields
+      ? normalizeCoordinates(
+          convertFieldUnits(request.fields, request.unit),
+          request.coordinateSystem,
+          pageHeight
+        )
+      : undefined;
+
+    // Build form data (recipients/fields serialized to JSON strings, as the n8n node does)
+    const formData: Record<string, any> = {
+      recipients: JSON.stringify(request.recipients),
+    };
+    if (fields) formData.fields = JSON.stringify(fields);
+    if (request.fieldLayoutId) formData.fieldLayoutId = request.fieldLayoutId;
+
+    // Add optional fields
+    if (request.documentName) formData.documentName = request.documentName;
+    if (request.documentDescription) formData.documentDescription = request.documentDescription;
+
+    // Use request senderEmail/senderName if provided, otherwise fall back to configured values
+    formData.senderEmail = request.senderEmail || senderConfig.senderEmail;
+    if (request.senderName || senderConfig.senderName) {
+      formData.senderName = request.senderName || senderConfig.senderName;
+    }
+
+    if (request.ccEmails) {
+      formData.ccEmails = Array.isArray(request.ccEmails)
+        ? JSON.stringify(request.ccEmails)
+        : JSON.stringify([request.ccEmails]);
+    }
+
+    if (request.redirectUrls) {
+      formData.redirectUrls = JSON.stringify(request.redirectUrls);
+    }
+
+    if (request.signatureLevel) {
+      formData.signatureLevel = request.signatureLevel;
+    }
+
+    if (request.timeZone) {
+      formData.timeZone = request.timeZone;
+    }
+
+    if (request.accessibility) {
+      formData.accessibility = JSON.stringify(request.accessibility);
+    }
+
+    if (request.customFields) {
+      formData.customFields = JSON.stringify(request.customFields);
+    }
+
+    if (request.mode) {
+      formData.mode = request.mode;
+    }
+
+    return formData;
   }
 
   // ============================================
@@ -90,47 +267,26 @@ export class TurboSign {
    * });
    * ```
    */
-  static async createSignatureReviewLink(request: CreateSignatureReviewLinkRequest): Promise<CreateSignatureReviewLinkResponse> {
+  async createSignatureReviewLink(request: CreateSignatureReviewLinkRequest): Promise<CreateSignatureReviewLinkResponse> {
     const client = this.getClient();
 
-    // Get sender config from client
-    const senderConfig = client.getSenderConfig();
-
-    // Serialize recipients and fields to JSON strings (as n8n node does)
-    const recipientsJson = JSON.stringify(request.recipients);
-    const fieldsJson = JSON.stringify(request.fields);
-
-    // Build form data
-    const formData: Record<string, any> = {
-      recipients: recipientsJson,
-      fields: fieldsJson,
-    };
-
-    // Add optional fields
-    if (request.documentName) formData.documentName = request.documentName;
-    if (request.documentDescription) formData.documentDescription = request.documentDescription;
-
-    // Use request senderEmail/senderName if provided, otherwise fall back to configured values
-    formData.senderEmail = request.senderEmail || senderConfig.senderEmail;
-    if (request.senderName || senderConfig.senderName) {
-      formData.senderName = request.senderName || senderConfig.senderName;
+    // Validate custom property values against the org schema before upload
+    if (request.customFields) {
+      await this.ensureValidCustomFields(request.customFields);
     }
 
-    if (request.ccEmails) {
-      formData.ccEmails = Array.isArray(request.ccEmails)
-        ? JSON.stringify(request.ccEmails)
-        : JSON.stringify([request.ccEmails]);
-    }
+    // Build the form payload (validates and serializes JSON fields exactly once)
+    const formData = this.buildSignaturePayload(request, client);
 
     // Handle different file input methods
     if (request.file) {
       // File upload - use multipart form
-      const response = await client.uploadFile<CreateSignatureReviewLinkResponse>(
-        '/turbosign/single/prepare-for-review',
+      const response = await this.op('TurboSign.createSignatureReviewLink', client.uploadFile<CreateSignatureReviewLinkResponse>(
+        Endpoints.sign.prepareForReview,
         request.file,
         'file',
         formData
-      );
+      ));
       return response;
     } else {
       // URL, deliverable, or template - use JSON body
@@ -138,10 +294,10 @@ export class TurboSign {
       if (request.deliverableId) formData.deliverableId = request.deliverableId;
       if (request.templateId) formData.templateId = request.templateId;
 
-      const response = await client.post<CreateSignatureReviewLinkResponse>(
-        '/turbosign/single/prepare-for-review',
+      const response = await this.op('TurboSign.createSignatureReviewLink', client.post<CreateSignatureReviewLinkResponse>(
+        Endpoints.sign.prepareForReview,
         formData
-      );
+      ));
       return response;
     }
   }
@@ -171,181 +327,1492 @@ export class TurboSign {
    * });
    * ```
    */
-  static async sendSignature(request: SendSignatureRequest): Promise<SendSignatureResponse> {
+  async sendSignature(request: SendSignatureRequest): Promise<SendSignatureResponse> {
     const client = this.getClient();
 
-    // Get sender config from client
-    const senderConfig = client.getSenderConfig();
+    // Validate custom property values against the org schema before upload
+    if (request.customFields) {
+      await this.ensureValidCustomFields(request.customFields);
+    }
+
+    // Build the form payload (validates and serializes JSON fields exactly once)
+    const formData = this.buildSignaturePayload(request, client);
+
+    // Handle different file input methods
+    if (request.file) {
+      // File upload - use multipart form
+      const response = await this.op('TurboSign.sendSignature', client.uploadFile<SendSignatureResponse>(
+        Endpoints.sign.prepareForSigning,
+        request.file,
+        'file',
+        formData
+      ));
+      return response;
+    } else {
+      // URL, deliverable, or template - use JSON body
+      if (request.fileLink) formData.fileLink = request.fileLink;
+      if (request.deliverableId) formData.deliverableId = request.deliverableId;
+      if (request.templateId) formData.templateId = request.templateId;
+
+      const response = await this.op('TurboSign.sendSignature', client.post<SendSignatureResponse>(
+        Endpoints.sign.prepareForSigning,
+        formData
+      ));
+      return response;
+    }
+  }
+
+  /**
+   * Send a document for receipt acknowledgment (e-delivery receipt)
+   *
+   * Recipients confirm they received the document instead of signing it —
+   * no signature fields are placed, and the document moves through its own
+   * status values ('pending_acknowledgment' through 'acknowledged'). Suited
+   * to policy-distribution use cases where a full signature ceremony is
+   * overkill.
+   *
+   * @param request - Document and recipients configuration (no fields)
+   * @returns Document with its acknowledgment status
+   *
+   * @example
+   * ```typescript
+   * const result = await TurboSign.sendForAcknowledgment({
+   *   file: policyPdfBuffer,
+   *   documentName: 'Remote Work Policy 2026',
+   *   recipients: [{ name: 'John Doe', email: 'john@example.com', signingOrder: 1 }]
+   * });
+   * console.log(result.status); // 'pending_acknowledgment'
+   * ```
+   */
+  async sendForAcknowledgment(request: SendForAcknowledgmentRequest): Promise<SendForAcknowledgmentResponse> {
+    const client = this.getClient();
+
+    // Validate custom property values against the org schema before upload
+    if (request.customFields) {
+      await this.ensureValidCustomFields(request.customFields);
+    }
+    if (request.timeZone) {
+      validateTimeZone(request.timeZone);
+    }
 
-    // Serialize recipients and fields to JSON strings (as n8n node does)
-    const recipientsJson = JSON.stringify(request.recipients);
-    const fieldsJson = JSON.stringify(request.fields);
+    const senderConfig = client.getSenderConfig();
 
-    // Build form data
+    // No fields to place, so the field validation/conversion pipeline is
+    // skipped entirely — this payload is recipients plus document metadata
     const formData: Record<string, any> = {
-      recipients: recipientsJson,
-      fields: fieldsJson,
+      recipients: JSON.stringify(request.recipients),
     };
-
-    // Add optional fields
     if (request.documentName) formData.documentName = request.documentName;
     if (request.documentDescription) formData.documentDescription = request.documentDescription;
-
-    // Use request senderEmail/senderName if provided, otherwise fall back to configured values
     formData.senderEmail = request.senderEmail || senderConfig.senderEmail;
     if (request.senderName || senderConfig.senderName) {
       formData.senderName = request.senderName || senderConfig.senderName;
     }
-
     if (request.ccEmails) {
       formData.ccEmails = Array.isArray(request.ccEmails)
         ? JSON.stringify(request.ccEmails)
         : JSON.stringify([request.ccEmails]);
     }
+    if (request.redirectUrls) {
+      formData.redirectUrls = JSON.stringify(request.redirectUrls);
+    }
+    if (request.timeZone) {
+      formData.timeZone = request.timeZone;
+    }
+    if (request.customFields) {
+      formData.customFields = JSON.stringify(request.customFields);
+    }
 
     // Handle different file input methods
     if (request.file) {
       // File upload - use multipart form
-      const response = await client.uploadFile<SendSignatureResponse>(
-        '/turbosign/single/prepare-for-signing',
+      return this.op('TurboSign.sendForAcknowledgment', client.uploadFile<SendForAcknowledgmentResponse>(
+        Endpoints.sign.sendForAcknowledgment,
         request.file,
         'file',
         formData
-      );
-      return response;
+      ));
     } else {
       // URL, deliverable, or template - use JSON body
       if (request.fileLink) formData.fileLink = request.fileLink;
       if (request.deliverableId) formData.deliverableId = request.deliverableId;
       if (request.templateId) formData.templateId = request.templateId;
 
-      const response = await client.post<SendSignatureResponse>(
-        '/turbosign/single/prepare-for-signing',
+      return this.op('TurboSign.sendForAcknowledgment', client.post<SendForAcknowledgmentResponse>(
+        Endpoints.sign.sendForAcknowledgment,
         formData
-      );
-      return response;
+      ));
     }
   }
 
-  // ============================================
-  // DOCUMENT MANAGEMENT
-  // ============================================
-
   /**
-   * Void a document (cancel signature request)
+   * Send one single-signer envelope per CSV row
    *
-   * @param documentId - ID of the document to void
-   * @param reason - Reason for voiding the document
-   * @returns Voided document details including status and timestamp
+   * The classic HR onboarding scenario: one template and saved field layout,
+   * a spreadsheet of recipients. Requires 'name' and 'email' columns; every
+   * other column becomes a custom document property on that row's envelope
+   * (values coerced using the org schema). Rows that fail validation or
+   * sending are collected in the report rather than aborting the batch, and
+   * sends are paced to stay under API rate limits, with one automatic retry
+   * after a rate-limit response.
+   *
+   * @param csv - CSV content with a header row ('name' and 'email' required)
+   * @param options - Template, layout, and pacing configuration
+   * @returns Per-row report with every row accounted for, in order
    *
    * @example
    * ```typescript
-   * const result = await TurboSign.void(documentId, 'Document needs to be revised');
-   * console.log(result.status); // "voided"
-   * console.log(result.voidedAt); // "2025-01-26T12:00:00.000Z"
+   * const report = await TurboSign.sendBulkFromCsv(csvText, {
+   *   templateId: 'offer-letter-template',
+   *   fieldLayoutId: 'layout-123',
+   *   documentName: 'Offer letter - {{name}}',
+   * });
+   * console.log(`${report.sent}/${report.total} sent`);
+   * for (const row of report.results) {
+   *   if (row.status === 'failed') console.warn(`Row ${row.row}: ${row.error}`);
+   * }
    * ```
    */
-  static async void(documentId: string, reason: string): Promise<VoidDocumentResponse> {
-    const client = this.getClient();
-    // HTTP client auto-unwraps {data: ...} responses
-    return client.post<VoidDocumentResponse>(
-      `/turbosign/documents/${documentId}/void`,
-      { reason }
+  async sendBulkFromCsv(csv: string | Buffer, options: BulkSendFromCsvOptions): Promise<BulkSendReport> {
+    const text = typeof csv === 'string' ? csv : csv.toString('utf8');
+    const records = parseCsvRecords(text);
+    if (records.length === 0) {
+      throw new ValidationError('CSV input has a header row but no data rows.');
+    }
+    for (const column of ['name', 'email']) {
+      if (!(column in records[0])) {
+        throw new ValidationError(`CSV is missing required column '${column}'. Expected at least: name, email.`);
+      }
+    }
+
+    const resolved = resolveBulkSendOptions(options);
+    const pacingMs = resolved.pacingMs;
+    const pacer = options.pacer;
+    const results: BulkRowResult[] = [];
+
+    for (let i = 0; i < records.length; i++) {
+      const record = records[i];
+      const row = i + 1;
+      const email = record.email?.trim() || undefined;
+
+      try {
+        const response = await this.sendBulkRow(record, options);
+        results.push({ row, email, status: 'sent', documentId: response.documentId });
+      } catch (error) {
+        if (error instanceof RateLimitError) {
+          // Back off and retry the row once before recording a failure
+          await this.sleep(pacingMs * 4);
+          try {
+            const response = await this.sendBulkRow(record, options);
+            results.push({ row, email, status: 'sent', documentId: response.documentId });
+          } catch (retryError) {
+            results.push({ row, email, status: 'failed', error: errorMessage(retryError) });
+          }
+        } else {
+          results.push({ row, email, status: 'failed', error: errorMessage(error) });
+        }
+      }
+
+      if (i < records.length - 1) {
+        if (pacer) {
+          await pacer.acquire();
+        } else if (pacingMs > 0) {
+          await this.sleep(pacingMs);
+        }
+      }
+    }
+
+    const sent = results.filter((result) => result.status === 'sent').length;
+    return { total: records.length, sent, failed: records.length - sent, results };
+  }
+
+  /** Validate one CSV record and send its envelope */
+  private async sendBulkRow(
+    record: Record<string, string>,
+    options: BulkSendFromCsvOptions
+  ): Promise<SendSignatureResponse> {
+    const name = record.name?.trim();
+    const email = record.email?.trim();
+    if (!name) {
+      throw new ValidationError('Missing name.');
+    }
+    if (!email || !email.includes('@')) {
+      throw new ValidationError(`'${record.email ?? ''}' is not an email address.`);
+    }
+
+    const extras: Record<string, string> = {};
+    for (const [key, value] of Object.entries(record)) {
+      if (key !== 'name' && key !== 'email' && value.trim() !== '') {
+        extras[key] = value;
+      }
+    }
+
+    // '{{column}}' placeholders in the document name are filled from the row
+    const documentName = options.documentName?.replace(
+      /\{\{\s*(\w+)\s*\}\}/g,
+      (_, column: string) => record[column] ?? ''
     );
+
+    return this.sendSignature({
+      templateId: options.templateId,
+      fieldLayoutId: options.fieldLayoutId,
+      recipients: [{ name, email, signingOrder: 1 }],
+      documentName,
+      senderEmail: options.senderEmail,
+      senderName: options.senderName,
+      customFields: Object.keys(extras).length > 0 ? await this.coerceCustomValues(extras) : undefined,
+    });
   }
 
   /**
-   * Resend signature request email to recipients
+   * Coerce CSV string cells using the org custom property schema so number
+   * and boolean fields validate. When the schema can't be fetched, strings
+   * pass through and per-row validation reports any mismatch.
+   */
+  private async coerceCustomValues(extras: Record<string, string>): Promise<CustomFieldValues> {
+    if (!this.customFieldDefinitions) {
+      try {
+        this.customFieldDefinitions = (await this.getCustomFieldDefinitions()).results;
+      } catch {
+        return extras;
+      }
+    }
+
+    const byKey = new Map(this.customFieldDefinitions.map((definition) => [definition.key, definition]));
+    const values: CustomFieldValues = {};
+    for (const [key, raw] of Object.entries(extras)) {
+      const definition = byKey.get(key);
+      if (definition?.type === 'number' && raw.trim() !== '' && !Number.isNaN(Number(raw))) {
+        values[key] = Number(raw);
+      } else if (definition?.type === 'boolean' && (raw === 'true' || raw === 'false')) {
+        values[key] = raw === 'true';
+      } else {
+        values[key] = raw;
+      }
+    }
+    return values;
+  }
+
+  private sleep(ms: number): Promise<void> {
+    return new Promise((resolve) => setTimeout(resolve, ms));
+  }
+
+  /**
+   * Get a server-side bulk job with its progress counters
    *
-   * @param documentId - ID of the document
-   * @param recipientIds - Array of recipient IDs to resend emails to
-   * @returns Resend confirmation with success and recipientCount
+   * Bulk operations the API queues (as opposed to the client-driven
+   * sendBulkFromCsv) report queued/sent/failed counts here, so large sends
+   * can be monitored without polling individual documents.
+   *
+   * @param jobId - ID of the bulk job
+   * @returns The job with its current status and progress
    *
    * @example
    * ```typescript
-   * // Resend to specific recipients
-   * const result = await TurboSign.resend(documentId, [recipientId1, recipientId2]);
-   * console.log(result.recipientCount); // 2
+   * const job = await TurboSign.getBulkJob('job-123');
+   * console.log(`${job.progress.sent}/${job.total} sent`);
    * ```
    */
-  static async resend(
-    documentId: string,
-    recipientIds: string[]
-  ): Promise<ResendEmailResponse> {
+  async getBulkJob(jobId: string): Promise<BulkJob> {
     const client = this.getClient();
     // HTTP client auto-unwraps {data: ...} responses
-    return client.post<ResendEmailResponse>(
-      `/turbosign/documents/${documentId}/resend-email`,
-      { recipientIds }
-    );
+    return this.op('TurboSign.getBulkJob', client.get<BulkJob>(Endpoints.sign.bulkJob(jobId)));
   }
 
   /**
-   * Get the audit trail for a document
+   * List server-side bulk jobs with pagination and status filtering
    *
-   * @param documentId - ID of the document
-   * @returns Audit trail with document info and entries
+   * @param options - Pagination and filter options
+   * @returns Paginated list of bulk jobs with total count
+   */
+  async listBulkJobs(options?: ListBulkJobsOptions): Promise<BulkJobListResponse> {
+    const client = this.getClient();
+    const params: Record<string, any> = {};
+
+    if (options) {
+      if (options.limit !== undefined) params.limit = options.limit;
+      if (options.offset !== undefined) params.offset = options.offset;
+      if (options.status !== undefined) params.status = options.status;
+    }
+
+    return this.op('TurboSign.listBulkJobs', client.get<BulkJobListResponse>(Endpoints.sign.bulkJobs, params));
+  }
+
+  /**
+   * Cancel a queued or in-progress bulk job
+   *
+   * Already-sent envelopes are unaffected; envelopes still queued are
+   * dropped. Cancelling a job in a terminal state is a no-op server-side.
+   *
+   * @param jobId - ID of the bulk job
+   * @returns The job with its updated status
+   */
+  async cancelBulkJob(jobId: string): Promise<BulkJob> {
+    const client = this.getClient();
+    return this.op('TurboSign.cancelBulkJob', client.post<BulkJob>(Endpoints.sign.cancelBulkJob(jobId)));
+  }
+
+  /**
+   * Save a field layout against a TurboDocx template
+   *
+   * Saved layouts can be referenced by `fieldLayoutId` on
+   * createSignatureReviewLink/sendSignature, avoiding re-shipping identical
+   * field arrays on every send of the same template.
+   *
+   * @param templateId - Template the layout belongs to
+   * @param fields - Fields to persist
+   * @returns The saved layout ID
    *
    * @example
    * ```typescript
-   * const audit = await TurboSign.getAuditTrail(documentId);
-   * console.log(audit.document.name);
-   * for (const entry of audit.auditTrail) {
-   *   console.log(`${entry.actionType} - ${entry.timestamp}`);
-   * }
+   * const layout = await TurboSign.saveFieldLayout(templateId, fields);
+   * await TurboSign.sendSignature({
+   *   templateId,
+   *   fieldLayoutId: layout.layoutId,
+   *   recipients: [{ name: 'John Doe', email: 'john@example.com', signingOrder: 1 }],
+   * });
    * ```
    */
-  static async getAuditTrail(documentId: string): Promise<AuditTrailResponse> {
+  async saveFieldLayout(templateId: string, fields: Field[]): Promise<SaveFieldLayoutResponse> {
     const client = this.getClient();
+    validateTabOrder(fields);
+
     // HTTP client auto-unwraps {data: ...} responses
-    return client.get<AuditTrailResponse>(`/turbosign/documents/${documentId}/audit-trail`);
+    return this.op('TurboSign.saveFieldLayout', client.post<SaveFieldLayoutResponse>(
+      Endpoints.sign.fieldLayouts(templateId),
+      { fields }
+    ));
   }
 
+  // ============================================
+  // SENDER VERIFICATION
+  // ============================================
+
   /**
-   * Download the signed document
+   * List sender addresses verified (or pending verification) for the org
    *
-   * @param documentId - ID of the document
-   * @returns Response with the PDF file as Blob
+   * @returns Verified and pending sender addresses
    *
    * @example
    * ```typescript
-   * const blob = await TurboSign.download(documentId);
-   * // Save to file or process the PDF
+   * const { results } = await TurboSign.listVerifiedSenders();
+   * const verified = results.filter((s) => s.status === 'verified');
    * ```
    */
-  static async download(documentId: string): Promise<Blob> {
+  async listVerifiedSenders(): Promise<ListVerifiedSendersResponse> {
     const client = this.getClient();
-    // Step 1: Get the presigned URL from the API
-    const response = await client.get<{ downloadUrl: string; fileName: string }>(
-      `/turbosign/documents/${documentId}/download`
-    );
-
-    // Step 2: Fetch the actual file from S3
-    const fileResponse = await fetch(response.downloadUrl);
-    if (!fileResponse.ok) {
-      throw new Error(`Failed to download file: ${fileResponse.statusText}`);
-    }
-
-    // Step 3: Return as Blob
-    const arrayBuffer = await fileResponse.arrayBuffer();
-    return new Blob([arrayBuffer], { type: 'application/pdf' });
+    return this.op('TurboSign.listVerifiedSenders', client.get<ListVerifiedSendersResponse>(
+      Endpoints.sign.verifiedSenders
+    ));
   }
 
   /**
-   * Get the status of a document
+   * Request (or re-check) verification for a sender address
    *
-   * @param documentId - ID of the document
-   * @returns Document status
+   * Lets automation confirm an address is verified before sending — an
+   * unverified senderEmail silently falls back to "API Service User via
+   * TurboSign" in recipient inboxes.
+   *
+   * @param email - Sender address to verify
+   * @returns Verification state after the request
    *
    * @example
    * ```typescript
-   * const status = await TurboSign.getStatus(documentId);
-   * console.log(status.status); // 'under_review', 'completed', 'voided', etc.
+   * const { status } = await TurboSign.verifySender('support@yourcompany.com');
+   * if (status !== 'verified') {
+   *   throw new Error('Sender not verified yet — check the inbox for the verification email');
+   * }
    * ```
    */
-  static async getStatus(documentId: string): Promise<DocumentStatusResponse> {
+  async verifySender(email: string): Promise<VerifySenderResponse> {
     const client = this.getClient();
-    // HTTP client auto-unwraps {data: ...} responses
-    return client.get<DocumentStatusResponse>(`/turbosign/documents/${documentId}/status`);
+    return this.op('TurboSign.verifySender', client.post<VerifySenderResponse>(
+      Endpoints.sign.verifySender,
+      { email }
+    ));
+  }
+
+  // ============================================
+  // CUSTOM FIELDS
+  // ============================================
+
+  /**
+   * Fetch the org-defined custom document property schema
+   *
+   * Orgs can define custom properties (e.g. cost center, contract type)
+   * that documents carry as metadata. Values are set via `customFields` on
+   * createSignatureReviewLink/sendSignature and validated client-side
+   * against this schema before upload.
+   *
+   * @returns The org's custom property definitions
+   *
+   * @example
+   * ```typescript
+   * const { results } = await TurboSign.getCustomFieldDefinitions();
+   * const required = results.filter((d) => d.required).map((d) => d.key);
+   * ```
+   */
+  async getCustomFieldDefinitions(): Promise<CustomFieldDefinitionsResponse> {
+    const client = this.getClient();
+    return this.op('TurboSign.getCustomFieldDefinitions', client.get<CustomFieldDefinitionsResponse>(
+      Endpoints.sign.customFieldDefinitions
+    ));
+  }
+
+  /**
+   * Validate custom property values against the org schema, fetching the
+   * schema on first use and caching it for the client's lifetime (the
+   * schema changes rarely; reconfigure to pick up edits).
+   */
+  private async ensureValidCustomFields(values: CustomFieldValues): Promise<void> {
+    if (!this.customFieldDefinitions) {
+      this.customFieldDefinitions = (await this.getCustomFieldDefinitions()).results;
+    }
+    validateCustomFieldValues(values, this.customFieldDefinitions);
+  }
+
+  // ============================================
+  // DOCUMENT MANAGEMENT
+  // ============================================
+
+  /**
+   * List documents with pagination and filtering
+   *
+   * @param options - Pagination and filter options
+   * @returns Paginated list of documents with total count
+   *
+   * @example
+   * ```typescript
+   * // List active (non-archived) documents
+   * const { results } = await TurboSign.listDocuments({ archived: false });
+   *
+   * // List archived documents
+   * const archived = await TurboSign.listDocuments({ archived: true });
+   * ```
+   */
+  async listDocuments(options?: ListDocumentsOptions): Promise<DocumentListResponse> {
+    const client = this.getClient();
+    const params: Record<string, any> = {};
+
+    if (options) {
+      if (options.limit !== undefined) params.limit = options.limit;
+      if (options.offset !== undefined) params.offset = options.offset;
+      if (options.status !== undefined) params.status = options.status;
+      if (options.archived !== undefined) params.archived = options.archived;
+    }
+
+    return this.op('TurboSign.listDocuments', client.get<DocumentListResponse>(Endpoints.sign.documents, params));
+  }
+
+  /**
+   * Iterate all documents page by page
+   *
+   * Async generator that fetches one page at a time, so memory stays flat
+   * while walking an entire org's documents.
+   *
+   * Pass onResumeToken to receive a persistable token after each record;
+   * a later run can hand it back as resumeToken to continue from the exact
+   * record the previous run stopped at.
+   *
+   * @param options - Page size, filters, and resume behavior
+   * @yields One document at a time, in list order
+   *
+   * @example
+   * ```typescript
+   * for await (const doc of TurboSign.iterateDocuments({ status: 'completed' })) {
+   *   console.log(doc.name);
+   * }
+   * ```
+   */
+  async *iterateDocuments(options?: IterateDocumentsOptions): AsyncGenerator<DocumentListItem, void, undefined> {
+    const pageSize = options?.pageSize ?? 100;
+    let offset = options?.resumeToken !== undefined
+      ? decodeResumeToken(options.resumeToken).offset
+      : options?.offset ?? 0;
+
+    while (true) {
+      const page = await this.listDocuments({
+        limit: pageSize,
+        offset,
+        status: options?.status,
+        archived: options?.archived,
+      });
+
+      for (const doc of page.results) {
+        yield doc;
+        offset++;
+        options?.onResumeToken?.(encodeResumeToken({ offset }));
+      }
+
+      if (page.results.length < pageSize || offset >= page.totalRecords) {
+        return;
+      }
+    }
+  }
+
+  /**
+   * List in-flight documents that expire within the given number of days
+   *
+   * Useful for a daily job that nudges senders or recipients before
+   * envelopes lapse. Each result includes how many days remain.
+   *
+   * @param withinDays - Expiration window in days from now
+   * @returns Documents expiring within the window, soonest first
+   *
+   * @example
+   * ```typescript
+   * const { results } = await TurboSign.listExpiring(7);
+   * for (const doc of results) {
+   *   console.log(`${doc.name} expires in ${doc.daysRemaining} days`);
+   * }
+   * ```
+   */
+  async listExpiring(withinDays: number): Promise<ExpiringDocumentsResponse> {
+    const client = this.getClient();
+    return this.op('TurboSign.listExpiring', client.get<ExpiringDocumentsResponse>(Endpoints.sign.documentsExpiring, {
+      withinDays,
+    }));
+  }
+
+  /**
+   * Archive a document, moving it out of the active view
+   *
+   * Archived documents no longer appear in default list results but remain
+   * fully accessible by ID. Use {@link unarchiveDocument} to restore.
+   *
+   * @param documentId - ID of the document to archive
+   * @returns Archived document details
+   *
+   * @example
+   * ```typescript
+   * const result = await TurboSign.archiveDocument(documentId);
+   * console.log(result.archived); // true
+   * ```
+   */
+  async archiveDocument(documentId: string): Promise<ArchiveDocumentResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.archiveDocument', client.post<ArchiveDocumentResponse>(
+      Endpoints.sign.archive(documentId)
+    ));
+  }
+
+  /**
+   * Restore an archived document to the active view
+   *
+   * @param documentId - ID of the document to restore
+   * @returns Restored document details
+   *
+   * @example
+   * ```typescript
+   * const result = await TurboSign.unarchiveDocument(documentId);
+   * console.log(result.archived); // false
+   * ```
+   */
+  async unarchiveDocument(documentId: string): Promise<ArchiveDocumentResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.unarchiveDocument', client.post<ArchiveDocumentResponse>(
+      Endpoints.sign.unarchive(documentId)
+    ));
+  }
+
+  /**
+   * Clone an existing document for a repeat engagement
+   *
+   * Duplicates the document file, fields, and settings of a past envelope
+   * so it can be sent again (e.g. annual renewals) without re-submitting
+   * the original request.
+   *
+   * @param documentId - ID of the document to clone
+   * @param options - Optional overrides such as replacement recipients
+   * @returns The newly created document with its ID and status
+   *
+   * @example
+   * ```typescript
+   * const result = await TurboSign.cloneDocument(documentId, {
+   *   newRecipients: [{ name: 'Jane Smith', email: 'jane@example.com', signingOrder: 1 }]
+   * });
+   * console.log(result.documentId); // ID of the cloned document
+   * ```
+   */
+  async cloneDocument(documentId: string, options?: CloneDocumentOptions): Promise<CloneDocumentResponse> {
+    const client = this.getClient();
+    const body: Record<string, any> = {};
+    if (options?.newRecipients) body.newRecipients = options.newRecipients;
+    if (options?.documentName) body.documentName = options.documentName;
+
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.cloneDocument', client.post<CloneDocumentResponse>(
+      Endpoints.sign.clone(documentId),
+      body
+    ));
+  }
+
+  /**
+   * Void a document (cancel signature request)
+   *
+   * For automations racing real signers, pass expectedVersion (from
+   * getStatus) so the void only lands if the document hasn't changed since
+   * it was read — a mismatch fails with ConflictError instead of voiding a
+   * document that was just signed.
+   *
+   * Before sending, the document's status is checked client-side: voiding a
+   * document in a terminal status (completed, voided, declined) fails with
+   * InvalidStateError naming the current status, instead of the server's
+   * generic 400. Pass force: true to skip the check and let the server
+   * decide.
+   *
+   * @param documentId - ID of the document to void
+   * @param reason - Reason for voiding the document
+   * @param options - Optimistic concurrency precondition and pre-check control
+   * @returns Voided document details including status and timestamp
+   *
+   * @example
+   * ```typescript
+   * const { version } = await TurboSign.getStatus(documentId);
+   * const result = await TurboSign.void(documentId, 'Document needs to be revised', {
+   *   expectedVersion: version,
+   * });
+   * console.log(result.status); // "voided"
+   * ```
+   */
+  async void(documentId: string, reason: string, options?: VoidDocumentOptions): Promise<VoidDocumentResponse> {
+    const client = this.getClient();
+    if (!options?.force) {
+      await this.op('TurboSign.void', this.assertVoidable(documentId));
+    }
+    const body: Record<string, any> = { reason };
+    if (options?.expectedVersion !== undefined) {
+      body.expectedVersion = options.expectedVersion;
+    }
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.void', client.post<VoidDocumentResponse>(
+      Endpoints.sign.void(documentId),
+      body
+    ));
+  }
+
+  /**
+   * Client-side guard for void: a document in a terminal status can never
+   * be voided, so fail early with the current status named in the error.
+   * Best-effort — when the status read fails or comes back without a
+   * status, the request goes through and the server stays the authority.
+   */
+  private async assertVoidable(documentId: string): Promise<void> {
+    let status: string | undefined;
+    try {
+      const current = await this.getClient().get<DocumentStatusResponse>(
+        Endpoints.sign.status(documentId)
+      );
+      status = current?.status;
+    } catch {
+      return;
+    }
+    if (status && !canDocumentTransition(status, 'voided')) {
+      throw new InvalidStateError(
+        `Cannot void a ${status} document`,
+        status
+      ).withHelp('pass { force: true } to skip the client-side check and let the server decide');
+    }
+  }
+
+  /**
+   * Resend signature request email to recipients
+   *
+   * @param documentId - ID of the document
+   * @param recipientIds - Array of recipient IDs to resend emails to
+   * @returns Resend confirmation with success and recipientCount
+   *
+   * @example
+   * ```typescript
+   * // Resend to specific recipients
+   * const result = await TurboSign.resend(documentId, [recipientId1, recipientId2]);
+   * console.log(result.recipientCount); // 2
+   * ```
+   */
+  async resend(
+    documentId: string,
+    recipientIds: string[]
+  ): Promise<ResendEmailResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.resend', client.post<ResendEmailResponse>(
+      Endpoints.sign.resendEmail(documentId),
+      { recipientIds }
+    ));
+  }
+
+  /**
+   * Get the audit trail for a document
+   *
+   * @param documentId - ID of the document
+   * @returns Audit trail with document info and entries
+   *
+   * @example
+   * ```typescript
+   * const audit = await TurboSign.getAuditTrail(documentId);
+   * console.log(audit.document.name);
+   * for (const entry of audit.auditTrail) {
+   *   console.log(`${entry.actionType} - ${entry.timestamp}`);
+   * }
+   * ```
+   */
+  async getAuditTrail(documentId: string): Promise<AuditTrailResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.getAuditTrail', client.get<AuditTrailResponse>(Endpoints.sign.auditTrail(documentId)));
+  }
+
+  /** Like getAuditTrail, but wrapped in ApiResponse metadata (see getStatusWithResponse) */
+  async getAuditTrailWithResponse(documentId: string): Promise<ApiResponse<AuditTrailResponse>> {
+    const client = this.getClient();
+    return this.op('TurboSign.getAuditTrail', client.requestWithResponse<AuditTrailResponse>(
+      'GET',
+      Endpoints.sign.auditTrail(documentId)
+    ));
+  }
+
+  /**
+   * Get per-recipient email delivery status for a document
+   *
+   * Exposes whether each signature request email was delivered, bounced, or
+   * spam-flagged, so ops tooling can proactively switch to a corrected email
+   * instead of waiting for a customer complaint.
+   *
+   * @param documentId - ID of the document
+   * @returns Delivery state per recipient
+   *
+   * @example
+   * ```typescript
+   * const status = await TurboSign.getEmailStatus(documentId);
+   * const bounced = status.recipients.filter(r => r.state === 'bounced');
+   * ```
+   */
+  async getEmailStatus(documentId: string): Promise<EmailStatusResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.getEmailStatus', client.get<EmailStatusResponse>(
+      Endpoints.sign.emailStatus(documentId)
+    ));
+  }
+
+  /**
+   * Get the original send request for a document, as it was submitted
+   *
+   * Returns the recipients, fields, and settings from the original
+   * createSignatureReviewLink/sendSignature call, so post-hoc debugging
+   * ("what coordinates did we send?") doesn't depend on logging every
+   * payload yourself.
+   *
+   * @param documentId - ID of the document
+   * @returns The submitted recipients, fields, and settings
+   *
+   * @example
+   * ```typescript
+   * const original = await TurboSign.getOriginalRequest(documentId);
+   * console.log(original.fields[0].x, original.fields[0].y);
+   * ```
+   */
+  async getOriginalRequest(documentId: string): Promise<OriginalRequestResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.getOriginalRequest', client.get<OriginalRequestResponse>(
+      Endpoints.sign.originalRequest(documentId)
+    ));
+  }
+
+  /**
+   * Download the signed document
+   *
+   * @param documentId - ID of the document
+   * @param options - Per-call request options (signal, deadline, timeout)
+   * @returns Response with the PDF file as Blob
+   *
+   * @example
+   * ```typescript
+   * const blob = await TurboSign.download(documentId);
+   * // Save to file or process the PDF
+   * ```
+   */
+  async download(documentId: string, options?: RequestOptions): Promise<Blob> {
+    const client = this.getClient();
+    // Step 1: Get the presigned URL from the API
+    const response = await this.op('TurboSign.download', client.get<{ downloadUrl: string; fileName: string }>(
+      Endpoints.sign.download(documentId),
+      undefined,
+      options
+    ));
+
+    // Step 2: Fetch the actual file from S3. The caller's signal is
+    // forwarded so aborting cancels the transfer itself, not just step 1.
+    const fileResponse = await fetch(response.downloadUrl, { signal: options?.signal });
+    if (!fileResponse.ok) {
+      throw new Error(`Failed to download file: ${fileResponse.statusText}`);
+    }
+
+    // Step 3: Validate against the storage-provided content hash, then return as Blob
+    const arrayBuffer = await fileResponse.arrayBuffer();
+    verifyDownloadIntegrity(arrayBuffer, fileResponse);
+    return new Blob([arrayBuffer], { type: 'application/pdf' });
+  }
+
+  /**
+   * Download the signed document straight to a file on disk
+   *
+   * Streams the PDF to the given path instead of buffering it in memory,
+   * for memory-constrained environments handling occasional huge documents.
+   *
+   * @param documentId - ID of the document
+   * @param filePath - Local path to write the PDF to
+   * @param options - Per-call request options (signal, deadline, timeout)
+   * @returns The file path and number of bytes written
+   *
+   * @example
+   * ```typescript
+   * const { bytesWritten } = await TurboSign.downloadToFile(documentId, '/tmp/signed.pdf');
+   * ```
+   */
+  async downloadToFile(documentId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
+    const client = this.getClient();
+    // Step 1: Get the presigned URL from the API
+    const response = await this.op('TurboSign.downloadToFile', client.get<{ downloadUrl: string; fileName: string }>(
+      Endpoints.sign.download(documentId),
+      undefined,
+      options
+    ));
+
+    // Step 2: Stream the actual file from S3 to disk. The caller's signal
+    // is forwarded so aborting cancels the transfer itself, not just step 1.
+    const fileResponse = await fetch(response.downloadUrl, { signal: options?.signal });
+    if (!fileResponse.ok) {
+      throw new Error(`Failed to download file: ${fileResponse.statusText}`);
+    }
+
+    return streamResponseToFile(fileResponse, filePath);
+  }
+
+  /**
+   * Get the status of a document
+   *
+   * @param documentId - ID of the document
+   * @returns Document status
+   *
+   * @example
+   * ```typescript
+   * const status = await TurboSign.getStatus(documentId);
+   * console.log(status.status); // 'under_review', 'completed', 'voided', etc.
+   * ```
+   */
+  async getStatus(documentId: string): Promise<DocumentStatusResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.getStatus', client.get<DocumentStatusResponse>(Endpoints.sign.status(documentId)));
+  }
+
+  /**
+   * Get the data submitted into a document's fields
+   *
+   * Returns one entry per field with its submitted value, so form-mode
+   * documents work as a typed form collector: send with mode 'form', wait
+   * for completion, then read the collected answers here. Works for
+   * signature documents too (text/checkbox/dropdown fields filled during
+   * the ceremony).
+   *
+   * @param documentId - ID of the document
+   * @returns Submitted values, one entry per field
+   *
+   * @example
+   * ```typescript
+   * const values = await TurboSign.getFieldValues(documentId);
+   * if (values.complete) {
+   *   for (const field of values.results) console.log(field.label, field.value);
+   * }
+   * ```
+   */
+  async getFieldValues(documentId: string): Promise<FieldValuesResponse> {
+    const client = this.getClient();
+    // HTTP client auto-unwraps {data: ...} responses
+    return this.op('TurboSign.getFieldValues', client.get<FieldValuesResponse>(Endpoints.sign.fieldValues(documentId)));
+  }
+
+  /**
+   * Like getStatus, but returns the HTTP status, headers, and request ID
+   * alongside the typed result — for callers that need rate-limit counters
+   * or a request ID for support tickets.
+   *
+   * @param documentId - ID of the document
+   * @returns Status wrapped in ApiResponse metadata
+   *
+   * @example
+   * ```typescript
+   * const { data, headers, requestId } = await TurboSign.getStatusWithResponse(documentId);
+   * console.log(data.status, headers['x-ratelimit-remaining'], requestId);
+   * ```
+   */
+  async getStatusWithResponse(documentId: string): Promise<ApiResponse<DocumentStatusResponse>> {
+    const client = this.getClient();
+    return this.op('TurboSign.getStatus', client.requestWithResponse<DocumentStatusResponse>(
+      'GET',
+      Endpoints.sign.status(documentId)
+    ));
+  }
+
+  /**
+   * Get the status of many documents at once
+   *
+   * Issues the status requests concurrently (in batches of 25) over the
+   * client's keep-alive connection, so polling hundreds of in-flight
+   * documents takes seconds instead of minutes.
+   *
+   * @param documentIds - IDs of the documents to poll
+   * @returns Map of document ID to its status
+   *
+   * @example
+   * ```typescript
+   * const statuses = await TurboSign.getStatuses(['doc-1', 'doc-2']);
+   * console.log(statuses['doc-1'].status);
+   * ```
+   */
+  async getStatuses(documentIds: string[]): Promise<Record<string, DocumentStatusResponse>> {
+    const statuses: Record<string, DocumentStatusResponse> = {};
+    const batchSize = 25;
+
+    for (let i = 0; i < documentIds.length; i += batchSize) {
+      const batch = documentIds.slice(i, i + batchSize);
+      const results = await Promise.all(batch.map((id) => this.getStatus(id)));
+      batch.forEach((id, index) => {
+        statuses[id] = results[index];
+      });
+    }
+
+    return statuses;
+  }
+
+  /**
+   * Estimate the cost of a planned batch before executing it
+   *
+   * Wraps the pricing/estimation endpoint so finance-sensitive batch sends
+   * can surface envelope/page counts and credit consumption (and whether
+   * the batch fits the org's plan) before a single envelope goes out.
+   *
+   * @param request - Shape of the planned batch
+   * @returns Estimated counts and credit consumption
+   *
+   * @example
+   * ```typescript
+   * const estimate = await TurboSign.estimateCost({ envelopeCount: 500, pagesPerDocument: 4 });
+   * if (!estimate.withinPlanLimits) {
+   *   throw new Error(`Batch needs ${estimate.estimatedCredits} credits, only ${estimate.creditsRemaining} left`);
+   * }
+   * ```
+   */
+  async estimateCost(request: CostEstimateRequest): Promise<CostEstimateResponse> {
+    const client = this.getClient();
+    return this.op('TurboSign.estimateCost', client.post<CostEstimateResponse>(
+      Endpoints.sign.estimate,
+      request
+    ));
+  }
+
+  /**
+   * Check a planned batch against the org's remaining quota
+   *
+   * Builds on estimateCost: 'exceeded' when the batch doesn't fit the plan
+   * or would consume more credits than remain, 'low' when the credits left
+   * afterwards would drop below lowWaterMark, 'ok' otherwise. Batch drivers
+   * call this between chunks so they can pause or degrade instead of
+   * burning the quota and failing the tail of the batch.
+   *
+   * @param request - Shape of the planned batch
+   * @param lowWaterMark - Remaining-credit level below which the check reports 'low' (default 0)
+   * @returns Quota position including credits before and after the batch
+   */
+  async checkQuota(request: CostEstimateRequest, lowWaterMark: number = 0): Promise<QuotaCheck> {
+    const estimate = await this.estimateCost(request);
+    const { estimatedCredits, creditsRemaining } = estimate;
+    const creditsAfter = creditsRemaining !== undefined ? creditsRemaining - estimatedCredits : undefined;
+
+    let status: QuotaStatus = 'ok';
+    if (!estimate.withinPlanLimits || (creditsAfter !== undefined && creditsAfter < 0)) {
+      status = 'exceeded';
+    } else if (creditsAfter !== undefined && creditsAfter < lowWaterMark) {
+      status = 'low';
+    }
+
+    return { status, estimatedCredits, creditsRemaining, creditsAfter };
+  }
+
+  /**
+   * Assert that a planned batch fits the org's remaining quota
+   *
+   * Throwing variant of checkQuota for use as a guard at the top of a batch
+   * or between chunks: QuotaExceededError when the batch doesn't fit,
+   * QuotaLowError when it would drop remaining credits below lowWaterMark.
+   * Both carry the credit numbers for reporting.
+   *
+   * @param request - Shape of the planned batch
+   * @param lowWaterMark - Remaining-credit level below which QuotaLowError is thrown (default 0)
+   * @returns Quota position when the batch fits
+   *
+   * @example
+   * ```typescript
+   * await TurboSign.assertQuota({ envelopeCount: rows.length }, 100);
+   * for (const row of rows) {
+   *   await TurboSign.sendSignature(buildRequest(row));
+   * }
+   * ```
+   */
+  async assertQuota(request: CostEstimateRequest, lowWaterMark: number = 0): Promise<QuotaCheck> {
+    const check = await this.checkQuota(request, lowWaterMark);
+    if (check.status === 'exceeded') {
+      throw new QuotaExceededError(
+        `Batch needs ${check.estimatedCredits} credits but only ${check.creditsRemaining ?? 0} remain on the plan`,
+        check.estimatedCredits,
+        check.creditsRemaining
+      );
+    }
+    if (check.status === 'low') {
+      throw new QuotaLowError(
+        `Batch would leave ${check.creditsAfter} credits, below the low-water mark of ${lowWaterMark}`,
+        check.estimatedCredits,
+        check.creditsRemaining
+      );
+    }
+    return check;
+  }
+
+  /**
+   * Simulate a recipient action on a document (sandbox only)
+   *
+   * Lets end-to-end tests drive a document through viewing, signing, or
+   * declining without a human clicking email links. The API rejects this
+   * call outside sandbox environments.
+   *
+   * @param documentId - ID of the document
+   * @param recipientId - Recipient to act as
+   * @param action - Action to simulate
+   * @returns Document status after the action
+   *
+   * @example
+   * ```typescript
+   * for (const recipient of recipients) {
+   *   await TurboSign.simulateRecipientAction(documentId, recipient.id, 'sign');
+   * }
+   * const { status } = await TurboSign.getStatus(documentId); // 'completed'
+   * ```
+   */
+  async simulateRecipientAction(
+    documentId: string,
+    recipientId: string,
+    action: RecipientAction
+  ): Promise<SimulateRecipientActionResponse> {
+    const client = this.getClient();
+    return this.op('TurboSign.simulateRecipientAction', client.post<SimulateRecipientActionResponse>(
+      Endpoints.sign.simulateRecipientAction(documentId),
+      { recipientId, action }
+    ));
+  }
+
+  /**
+   * Watch a set of documents and yield status changes as they happen
+   *
+   * Polls getStatuses under the hood with an adaptive interval: it resets to
+   * pollIntervalMs whenever a change is observed and doubles (up to
+   * maxPollIntervalMs) while nothing moves, with jitter so many watchers
+   * don't poll in lockstep. Each document's first emission reports its
+   * initial status; after that, only state changes are yielded. By default
+   * the generator completes once every document reaches a terminal status
+   * (completed, voided, or declined).
+   *
+   * @param documentIds - IDs of the documents to watch
+   * @param options - Polling cadence and stop behavior
+   * @returns Async generator of status changes
+   *
+   * @example
+   * ```typescript
+   * for await (const change of TurboSign.watch(['doc-1', 'doc-2'])) {
+   *   console.log(`${change.documentId}: ${change.previousStatus} -> ${change.status}`);
+   * }
+   * ```
+   */
+  async *watch(documentIds: string[], options?: WatchOptions): AsyncGenerator<DocumentStatusChange, void, undefined> {
+    const { pollIntervalMs, maxPollIntervalMs, stopAtTerminal } = resolveWatchOptions(options);
+
+    const lastSeen: Record<string, string | undefined> = {};
+    const pending = new Set(documentIds);
+    let intervalMs = pollIntervalMs;
+
+    // Subscribe to shutdown once for the whole watch: a .then() inside the
+    // poll loop would append a reaction to the shared shutdown promise every
+    // cycle, and those accumulate unbounded on a watcher that runs for days.
+    // Each sleep below installs itself as the wake target instead.
+    let wakeForShutdown: (() => void) | undefined;
+    void this.client.whenShutdown()?.then(() => wakeForShutdown?.());
+
+    while (pending.size > 0) {
+      if (this.client.isShuttingDown()) {
+        return;
+      }
+      const statuses = await this.getStatuses(Array.from(pending));
+
+      let changed = false;
+      for (const documentId of Array.from(pending)) {
+        const status = statuses[documentId]?.status;
+        if (status === undefined || status === lastSeen[documentId]) {
+          continue;
+        }
+
+        changed = true;
+        yield { documentId, status, previousStatus: lastSeen[documentId] };
+        lastSeen[documentId] = status;
+
+        if (stopAtTerminal && isTerminalDocumentStatus(status)) {
+          pending.delete(documentId);
+        }
+      }
+
+      if (pending.size === 0) {
+        return;
+      }
+
+      // Adaptive cadence: poll fast while documents are moving, back off
+      // while everything is quiet
+      intervalMs = changed ? pollIntervalMs : Math.min(intervalMs * 2, maxPollIntervalMs);
+
+      // +/-10% jitter spreads out watchers started at the same moment
+      const jitter = intervalMs * 0.1 * (Math.random() * 2 - 1);
+      // Race the sleep against shutdown so termination isn't held up by a
+      // long backed-off interval
+      await new Promise<void>((resolve) => {
+        // The single subscription fires exactly once; if shutdown already
+        // happened (e.g. during the poll above) skip the sleep entirely
+        if (this.client.isShuttingDown()) {
+          resolve();
+          return;
+        }
+        const timer = setTimeout(() => {
+          wakeForShutdown = undefined;
+          resolve();
+        }, Math.max(0, Math.round(intervalMs + jitter)));
+        wakeForShutdown = () => {
+          clearTimeout(timer);
+          resolve();
+        };
+      });
+      if (this.client.isShuttingDown()) {
+        return;
+      }
+    }
+  }
+
+  /**
+   * Drain this client for clean termination, e.g. from a SIGTERM handler.
+   * New requests are rejected, active watch() loops stop at their next
+   * wake-up, and the promise resolves once in-flight requests settle or
+   * graceMs elapses. See {@link HttpClient.shutdown}.
+   *
+   * @param graceMs - How long to wait for in-flight requests (default 10000)
+   */
+  shutdown(graceMs?: number): Promise<void> {
+    return this.client.shutdown(graceMs);
+  }
+}
+
+/**
+ * TurboSign Module - Digital signature operations (static facade)
+ *
+ * Thin wrapper around a single shared TurboSignClient. Configure once, then
+ * call static methods. Construct TurboSignClient directly to talk to several
+ * orgs or environments from the same process.
+ */
+export class TurboSign {
+  private static client: TurboSignClient;
+  private static config: HttpClientConfig = {};
+  private static profiles: Map<string, TurboSignClient> = new Map();
+  private static orgClients: Map<string, TurboSignClient> = new Map();
+
+  /**
+   * Configure the TurboSign module with API credentials
+   *
+   * @param config - Configuration object
+   * @param config.apiKey - TurboDocx API key (required)
+   * @param config.orgId - Organization ID (required)
+   * @param config.senderEmail - Reply-to email address for signature requests (required). This email will be used as the reply-to address when sending signature request emails. Without it, emails will default to "API Service User via TurboSign".
+   * @param config.senderName - Sender name for signature requests (optional but strongly recommended). This name will appear in signature request emails. Without this, the sender will appear as "API Service User".
+   * @param config.baseUrl - API base URL (optional, defaults to https://api.turbodocx.com)
+   *
+   * @example
+   * ```typescript
+   * TurboSign.configure({
+   *   apiKey: process.env.TURBODOCX_API_KEY,
+   *   orgId: process.env.TURBODOCX_ORG_ID,
+   *   senderEmail: 'support@yourcompany.com',
+   *   senderName: 'Your Company Name'  // Strongly recommended
+   * });
+   * ```
+   */
+  static configure(config: HttpClientConfig): void {
+    this.client = new TurboSignClient(config);
+    this.config = config;
+    // Org-scoped handles inherit the default config, so rebuild them lazily
+    this.orgClients = new Map();
+  }
+
+  /**
+   * Register a named credential profile alongside the default one
+   *
+   * For services operating several tenants (e.g. US and EU) from the same
+   * process: each profile holds its own client and credentials, and
+   * withProfile routes calls to it. The default profile (configure) is
+   * untouched.
+   *
+   * @param name - Profile name, e.g. 'eu'
+   * @param config - Credentials and settings for this profile
+   *
+   * @example
+   * ```typescript
+   * TurboSign.configure({ apiKey: usKey, orgId: usOrgId, senderEmail });
+   * TurboSign.configureProfile('eu', { apiKey: euKey, orgId: euOrgId, senderEmail, baseUrl: euBaseUrl });
+   * await TurboSign.withProfile('eu').sendSignature(request);
+   * ```
+   */
+  static configureProfile(name: string, config: HttpClientConfig): void {
+    this.profiles.set(name, new TurboSignClient(config));
+  }
+
+  /**
+   * Get the client for a named profile registered with configureProfile
+   *
+   * @param name - Profile name
+   * @returns The profile's client, with the full TurboSignClient API
+   * @throws ValidationError when no profile with that name exists
+   */
+  static withProfile(name: string): TurboSignClient {
+    const client = this.profiles.get(name);
+    if (!client) {
+      throw new ValidationError(
+        `No profile named '${name}'. Register it first with TurboSign.configureProfile('${name}', config).`
+      );
+    }
+    return client;
+  }
+
+  /**
+   * Get a handle scoped to another organization
+   *
+   * For multi-tenant backends that switch the x-rapiddocx-org-id per
+   * request: the handle reuses the default configure credentials with only
+   * the org swapped, so there is no global reconfiguration (and no race
+   * between tenants). Handles are cached per org and invalidated when
+   * configure is called again.
+   *
+   * @param orgId - Organization ID to act as
+   * @returns A client for that org, with the full TurboSignClient API
+   *
+   * @example
+   * ```typescript
+   * TurboSign.configure({ apiKey, orgId: defaultOrgId, senderEmail });
+   * await TurboSign.forOrg(tenantOrgId).sendSignature(request);
+   * ```
+   */
+  static forOrg(orgId: string): TurboSignClient {
+    let client = this.orgClients.get(orgId);
+    if (!client) {
+      client = new TurboSignClient({ ...this.config, orgId });
+      this.orgClients.set(orgId, client);
+    }
+    return client;
+  }
+
+  /**
+   * Get the shared client instance, auto-initializing from environment
+   * variables if configure was never called
+   */
+  private static getInstance(): TurboSignClient {
+    if (!this.client) {
+      this.client = new TurboSignClient();
+    }
+    return this.client;
+  }
+
+  /** See {@link TurboSignClient.warmUp} */
+  static warmUp(): Promise<void> {
+    return this.getInstance().warmUp();
+  }
+
+  /** See {@link TurboSignClient.createSignatureReviewLink} */
+  static createSignatureReviewLink(request: CreateSignatureReviewLinkRequest): Promise<CreateSignatureReviewLinkResponse> {
+    return this.getInstance().createSignatureReviewLink(request);
+  }
+
+  /** See {@link TurboSignClient.sendSignature} */
+  static sendSignature(request: SendSignatureRequest): Promise<SendSignatureResponse> {
+    return this.getInstance().sendSignature(request);
+  }
+
+  /** See {@link TurboSignClient.sendForAcknowledgment} */
+  static sendForAcknowledgment(request: SendForAcknowledgmentRequest): Promise<SendForAcknowledgmentResponse> {
+    return this.getInstance().sendForAcknowledgment(request);
+  }
+
+  /** See {@link TurboSignClient.sendBulkFromCsv} */
+  static sendBulkFromCsv(csv: string | Buffer, options: BulkSendFromCsvOptions): Promise<BulkSendReport> {
+    return this.getInstance().sendBulkFromCsv(csv, options);
+  }
+
+  /** See {@link TurboSignClient.getBulkJob} */
+  static getBulkJob(jobId: string): Promise<BulkJob> {
+    return this.getInstance().getBulkJob(jobId);
+  }
+
+  /** See {@link TurboSignClient.listBulkJobs} */
+  static listBulkJobs(options?: ListBulkJobsOptions): Promise<BulkJobListResponse> {
+    return this.getInstance().listBulkJobs(options);
+  }
+
+  /** See {@link TurboSignClient.cancelBulkJob} */
+  static cancelBulkJob(jobId: string): Promise<BulkJob> {
+    return this.getInstance().cancelBulkJob(jobId);
+  }
+
+  /** See {@link TurboSignClient.saveFieldLayout} */
+  static saveFieldLayout(templateId: string, fields: Field[]): Promise<SaveFieldLayoutResponse> {
+    return this.getInstance().saveFieldLayout(templateId, fields);
+  }
+
+  /** See {@link TurboSignClient.listVerifiedSenders} */
+  static listVerifiedSenders(): Promise<ListVerifiedSendersResponse> {
+    return this.getInstance().listVerifiedSenders();
+  }
+
+  /** See {@link TurboSignClient.verifySender} */
+  static verifySender(email: string): Promise<VerifySenderResponse> {
+    return this.getInstance().verifySender(email);
+  }
+
+  /** See {@link TurboSignClient.listDocuments} */
+  static listDocuments(options?: ListDocumentsOptions): Promise<DocumentListResponse> {
+    return this.getInstance().listDocuments(options);
+  }
+
+  /** See {@link TurboSignClient.iterateDocuments} */
+  static iterateDocuments(options?: IterateDocumentsOptions): AsyncGenerator<DocumentListItem, void, undefined> {
+    return this.getInstance().iterateDocuments(options);
+  }
+
+  /** See {@link TurboSignClient.listExpiring} */
+  static listExpiring(withinDays: number): Promise<ExpiringDocumentsResponse> {
+    return this.getInstance().listExpiring(withinDays);
+  }
+
+  /** See {@link TurboSignClient.archiveDocument} */
+  static archiveDocument(documentId: string): Promise<ArchiveDocumentResponse> {
+    return this.getInstance().archiveDocument(documentId);
+  }
+
+  /** See {@link TurboSignClient.unarchiveDocument} */
+  static unarchiveDocument(documentId: string): Promise<ArchiveDocumentResponse> {
+    return this.getInstance().unarchiveDocument(documentId);
+  }
+
+  /** See {@link TurboSignClient.cloneDocument} */
+  static cloneDocument(documentId: string, options?: CloneDocumentOptions): Promise<CloneDocumentResponse> {
+    return this.getInstance().cloneDocument(documentId, options);
+  }
+
+  /** See {@link TurboSignClient.void} */
+  static void(documentId: string, reason: string, options?: VoidDocumentOptions): Promise<VoidDocumentResponse> {
+    return this.getInstance().void(documentId, reason, options);
+  }
+
+  /** See {@link TurboSignClient.resend} */
+  static resend(documentId: string, recipientIds: string[]): Promise<ResendEmailResponse> {
+    return this.getInstance().resend(documentId, recipientIds);
+  }
+
+  /** See {@link TurboSignClient.getAuditTrail} */
+  static getAuditTrail(documentId: string): Promise<AuditTrailResponse> {
+    return this.getInstance().getAuditTrail(documentId);
+  }
+
+  /** See {@link TurboSignClient.getEmailStatus} */
+  static getEmailStatus(documentId: string): Promise<EmailStatusResponse> {
+    return this.getInstance().getEmailStatus(documentId);
+  }
+
+  /** See {@link TurboSignClient.getOriginalRequest} */
+  static getOriginalRequest(documentId: string): Promise<OriginalRequestResponse> {
+    return this.getInstance().getOriginalRequest(documentId);
+  }
+
+  /** See {@link TurboSignClient.download} */
+  static download(documentId: string, options?: RequestOptions): Promise<Blob> {
+    return this.getInstance().download(documentId, options);
+  }
+
+  /** See {@link TurboSignClient.downloadToFile} */
+  static downloadToFile(documentId: string, filePath: string, options?: RequestOptions): Promise<DownloadToFileResult> {
+    return this.getInstance().downloadToFile(documentId, filePath, options);
+  }
+
+  /** See {@link TurboSignClient.getStatus} */
+  static getStatus(documentId: string): Promise<DocumentStatusResponse> {
+    return this.getInstance().getStatus(documentId);
+  }
+
+  /** See {@link TurboSignClient.getFieldValues} */
+  static getFieldValues(documentId: string): Promise<FieldValuesResponse> {
+    return this.getInstance().getFieldValues(documentId);
+  }
+
+  /** See {@link TurboSignClient.getStatusWithResponse} */
+  static getStatusWithResponse(documentId: string): Promise<ApiResponse<DocumentStatusResponse>> {
+    return this.getInstance().getStatusWithResponse(documentId);
+  }
+
+  /** See {@link TurboSignClient.getAuditTrailWithResponse} */
+  static getAuditTrailWithResponse(documentId: string): Promise<ApiResponse<AuditTrailResponse>> {
+    return this.getInstance().getAuditTrailWithResponse(documentId);
+  }
+
+  /** See {@link TurboSignClient.getStatuses} */
+  static getStatuses(documentIds: string[]): Promise<Record<string, DocumentStatusResponse>> {
+    return this.getInstance().getStatuses(documentIds);
+  }
+
+  /** See {@link TurboSignClient.estimateCost} */
+  static estimateCost(request: CostEstimateRequest): Promise<CostEstimateResponse> {
+    return this.getInstance().estimateCost(request);
+  }
+
+  /** See {@link TurboSignClient.getCustomFieldDefinitions} */
+  static getCustomFieldDefinitions(): Promise<CustomFieldDefinitionsResponse> {
+    return this.getInstance().getCustomFieldDefinitions();
+  }
+
+  /** See {@link TurboSignClient.checkQuota} */
+  static checkQuota(request: CostEstimateRequest, lowWaterMark?: number): Promise<QuotaCheck> {
+    return this.getInstance().checkQuota(request, lowWaterMark);
+  }
+
+  /** See {@link TurboSignClient.assertQuota} */
+  static assertQuota(request: CostEstimateRequest, lowWaterMark?: number): Promise<QuotaCheck> {
+    return this.getInstance().assertQuota(request, lowWaterMark);
+  }
+
+  /** See {@link TurboSignClient.simulateRecipientAction} */
+  static simulateRecipientAction(documentId: string, recipientId: string, action: RecipientAction): Promise<SimulateRecipientActionResponse> {
+    return this.getInstance().simulateRecipientAction(documentId, recipientId, action);
+  }
+
+  /** See {@link TurboSignClient.watch} */
+  static watch(documentIds: string[], options?: WatchOptions): AsyncGenerator<DocumentStatusChange, void, undefined> {
+    return this.getInstance().watch(documentIds, options);
+  }
+
+  /** See {@link TurboSignClient.shutdown} */
+  static shutdown(graceMs?: number): Promise<void> {
+    return this.getInstance().shutdown(graceMs);
   }
 }
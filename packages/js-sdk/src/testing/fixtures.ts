@@ -0,0 +1,145 @@
+/**
+ * Test Fixtures
+ *
+ * Realistic response objects for downstream tests. The wide response
+ * interfaces are tedious to construct by hand; each fixture returns a
+ * complete, valid object and accepts partial overrides.
+ *
+ * Import from '@turbodocx/sdk/testing' — fixtures are not part of the
+ * runtime entry point.
+ */
+
+import {
+  AuditTrailResponse,
+  CreateSignatureReviewLinkResponse,
+  DocumentListItem,
+  SendSignatureResponse,
+} from '../types/sign';
+import {
+  CreateDeliverableResponse,
+  DeliverableRecord,
+} from '../types/deliverable';
+
+/**
+ * A completed DeliverableRecord with sensible defaults
+ */
+export function deliverableRecordFixture(overrides: Partial<DeliverableRecord> = {}): DeliverableRecord {
+  return {
+    id: 'deliverable-fixture-id',
+    name: 'Employee Contract - John Smith',
+    description: 'Employment contract',
+    templateId: 'template-fixture-id',
+    createdBy: 'user-fixture-id',
+    isActive: true,
+    createdOn: '2024-01-15T14:12:10.721Z',
+    updatedOn: '2024-01-15T14:12:10.721Z',
+    ...overrides,
+  };
+}
+
+/**
+ * A successful generateDeliverable response
+ */
+export function createDeliverableResponseFixture(
+  overrides: Partial<DeliverableRecord> = {}
+): CreateDeliverableResponse {
+  return {
+    results: {
+      deliverable: deliverableRecordFixture(overrides),
+    },
+  };
+}
+
+/**
+ * A successful createSignatureReviewLink response
+ */
+export function createSignatureReviewLinkResponseFixture(
+  overrides: Partial<CreateSignatureReviewLinkResponse> = {}
+): CreateSignatureReviewLinkResponse {
+  return {
+    success: true,
+    documentId: 'document-fixture-id',
+    status: 'ready_for_review',
+    previewUrl: 'https://app.turbodocx.com/review/document-fixture-id',
+    recipients: [
+      {
+        id: 'recipient-fixture-id',
+        name: 'John Doe',
+        email: 'john@example.com',
+      },
+    ],
+    message: 'Document prepared for review',
+    ...overrides,
+  };
+}
+
+/**
+ * A successful sendSignature response
+ */
+export function sendSignatureResponseFixture(
+  overrides: Partial<SendSignatureResponse> = {}
+): SendSignatureResponse {
+  return {
+    success: true,
+    documentId: 'document-fixture-id',
+    status: 'sent',
+    recipients: [
+      {
+        id: 'recipient-fixture-id',
+        name: 'John Doe',
+        email: 'john@example.com',
+      },
+    ],
+    message: 'Signature request sent',
+    ...overrides,
+  };
+}
+
+/**
+ * An audit trail with a prepared → sent → signed event chain
+ */
+export function auditTrailResponseFixture(
+  overrides: Partial<AuditTrailResponse> = {}
+): AuditTrailResponse {
+  return {
+    document: {
+      id: 'document-fixture-id',
+      name: 'Employee Contract - John Smith',
+    },
+    auditTrail: [
+      {
+        id: 'audit-entry-1',
+        documentId: 'document-fixture-id',
+        actionType: 'document_prepared',
+        timestamp: '2024-01-15T14:12:10.721Z',
+      },
+      {
+        id: 'audit-entry-2',
+        documentId: 'document-fixture-id',
+        actionType: 'email_sent',
+        timestamp: '2024-01-15T14:12:30.000Z',
+      },
+      {
+        id: 'audit-entry-3',
+        documentId: 'document-fixture-id',
+        actionType: 'document_signed',
+        timestamp: '2024-01-16T09:02:45.120Z',
+      },
+    ],
+    ...overrides,
+  };
+}
+
+/**
+ * A DocumentListItem as returned by listDocuments
+ */
+export function documentListItemFixture(overrides: Partial<DocumentListItem> = {}): DocumentListItem {
+  return {
+    id: 'document-fixture-id',
+    name: 'Employee Contract - John Smith',
+    status: 'sent',
+    createdOn: '2024-01-15T14:12:10.721Z',
+    updatedOn: '2024-01-15T14:12:30.000Z',
+    ...overrides,
+  };
+}
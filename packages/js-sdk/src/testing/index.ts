@@ -0,0 +1,5 @@
+/**
+ * Testing entry point — response fixtures for downstream test suites
+ */
+
+export * from './fixtures';
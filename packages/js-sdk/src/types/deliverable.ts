@@ -64,6 +64,10 @@ export interface DeliverableVariable {
   subvariables?: DeliverableVariable[];
   /** Multiple instances for repeating content (tables, lists) */
   variableStack?: Record<string, VariableStackEntry> | VariableStackEntry[];
+  /** Server-side formatting hint (e.g., "currency:USD", "date:longDate") */
+  format?: string;
+  /** Render this template's output into the placeholder (sub-template composition) */
+  templateId?: string;
   /** AI prompt for content generation (max 16,000 chars) */
   aiPrompt?: string;
   /** Whether to allow rich text injection */
@@ -89,6 +93,9 @@ export interface DeliverableConfig {
 // REQUEST TYPES
 // ============================================
 
+/** Queue priority for generation jobs */
+export type GenerationPriority = 'low' | 'normal' | 'high';
+
 export interface CreateDeliverableRequest {
   /** Deliverable name (3–255 characters) */
   name: string;
@@ -100,6 +107,25 @@ export interface CreateDeliverableRequest {
   description?: string;
   /** Array of tag strings to associate */
   tags?: string[];
+  /**
+   * Named template sections to include (engine section toggles).
+   * Sections not listed are omitted from the generated document.
+   */
+  includeSections?: string[];
+  /** Include per-variable substitution diagnostics in the response */
+  includeDiagnostics?: boolean;
+  /**
+   * Caller-supplied idempotency ID (e.g., "order-9182"). Generation is
+   * deduped per (templateId, externalId): retried jobs return the existing
+   * deliverable instead of creating a duplicate.
+   */
+  externalId?: string;
+  /**
+   * Queue priority (default 'normal'). Use 'high' for interactive
+   * user-facing renders so they jump ahead of nightly batch jobs from the
+   * same org, and 'low' for bulk work that can wait.
+   */
+  priority?: GenerationPriority;
 }
 
 export interface UpdateDeliverableRequest {
@@ -118,15 +144,44 @@ export interface ListDeliverablesOptions {
   offset?: number;
   /** Search query to filter by name */
   query?: string;
+  /** Only return deliverables carrying this tag */
+  tag?: string;
   /** Include tags in the response */
   showTags?: boolean;
 }
 
+export interface IterateDeliverablesOptions {
+  /** Number of records fetched per request (default 100) */
+  pageSize?: number;
+  /** Number of records to skip before the first yielded record */
+  offset?: number;
+  /** Search query to filter by name */
+  query?: string;
+  /** Include tags in the yielded records */
+  showTags?: boolean;
+  /** Opaque token from a previous run's onResumeToken — takes precedence over offset */
+  resumeToken?: string;
+  /** Called after each yielded record with a token that resumes from the next one */
+  onResumeToken?: (token: string) => void;
+}
+
 export interface GetDeliverableOptions {
   /** Include tags in the response */
   showTags?: boolean;
 }
 
+/**
+ * Options for createShareLink
+ */
+export interface ShareLinkOptions {
+  /** Lifetime of the link in seconds (omit for a non-expiring link) */
+  expiresIn?: number;
+  /** Whether viewers may download the file (default false, view only) */
+  allowDownload?: boolean;
+  /** Password required to open the link */
+  password?: string;
+}
+
 // ============================================
 // RESPONSE TYPES
 // ============================================
@@ -162,6 +217,8 @@ export interface DeliverableRecord {
   createdOn: string;
   /** ISO 8601 last update timestamp */
   updatedOn: string;
+  /** Caller-supplied idempotency ID, when one was set at generation */
+  externalId?: string;
   /** Parsed variable objects with values (only on getDeliverableDetails) */
   variables?: DeliverableVariable[];
   /** Tags (only when showTags=true) */
@@ -175,12 +232,79 @@ export interface DeliverableListResponse {
   totalRecords: number;
 }
 
+/** One TurboSign document generated from a deliverable */
+export interface DeliverableSignature {
+  /** TurboSign document ID */
+  documentId: string;
+  /** Document name */
+  name: string;
+  /** Current signing status */
+  status: string;
+  /** ISO 8601 timestamp the signature request was created */
+  createdOn?: string;
+}
+
+export interface DeliverableSignaturesResponse {
+  /** TurboSign documents generated from the deliverable */
+  results: DeliverableSignature[];
+}
+
+export interface VariableSubstitution {
+  /** Placeholder the count refers to */
+  placeholder: string;
+  /** Number of times the placeholder was substituted in the document */
+  count: number;
+}
+
+/**
+ * Per-generation diagnostics, returned when includeDiagnostics is set.
+ * The fastest way to find out why a template silently rendered nothing
+ * for a loop or left a placeholder untouched.
+ */
+export interface GenerationDiagnostics {
+  /** Substitution count per provided variable */
+  substitutions: VariableSubstitution[];
+  /** Placeholders found in the template with no matching variable */
+  unresolvedPlaceholders: string[];
+  /** Engine warnings emitted during generation */
+  warnings: string[];
+}
+
 export interface CreateDeliverableResponse {
   results: {
     deliverable: DeliverableRecord;
+    /** Present only when the request set includeDiagnostics */
+    diagnostics?: GenerationDiagnostics;
   };
 }
 
+export interface LockDeliverableResponse {
+  /** ID of the deliverable the lock applies to */
+  deliverableId: string;
+  /** Whether the deliverable is now locked */
+  locked: boolean;
+  /** Who holds the lock */
+  lockedBy?: string;
+  /** ISO 8601 timestamp the lock was taken */
+  lockedOn?: string;
+  /** Human-readable detail */
+  message?: string;
+}
+
+/** What cancelGeneration found when it reached the job */
+export type CancelGenerationOutcome = 'cancelled' | 'already_completed' | 'already_failed';
+
+export interface CancelGenerationResponse {
+  /** ID of the deliverable whose generation was targeted */
+  deliverableId: string;
+  /** Whether the job was actually stopped */
+  cancelled: boolean;
+  /** Why not, when cancelled is false: the job had already finished */
+  outcome: CancelGenerationOutcome;
+  /** Human-readable detail */
+  message?: string;
+}
+
 export interface UpdateDeliverableResponse {
   /** Success confirmation message */
   message: string;
@@ -188,6 +312,19 @@ export interface UpdateDeliverableResponse {
   deliverableId: string;
 }
 
+export interface ShareLinkResponse {
+  /** Shareable URL for the deliverable */
+  url: string;
+  /** ID of the deliverable the link points to */
+  deliverableId: string;
+  /** ISO 8601 expiration timestamp (absent for non-expiring links) */
+  expiresOn?: string;
+  /** Whether viewers may download the file */
+  allowDownload: boolean;
+  /** Whether the link is password protected */
+  passwordProtected: boolean;
+}
+
 export interface DeleteDeliverableResponse {
   /** Success confirmation message */
   message: string;
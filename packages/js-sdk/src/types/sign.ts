@@ -2,6 +2,22 @@
  * TypeScript types for TurboSign module
  */
 
+import type { Pacer } from '../utils/pacer';
+
+/**
+ * Origin used for field coordinates.
+ * - 'topLeft' (default): y grows downward from the top of the page (screen convention, what the API expects)
+ * - 'bottomLeft': y grows upward from the bottom of the page (PDF-native convention); converted internally
+ */
+export type CoordinateSystem = 'topLeft' | 'bottomLeft';
+
+/**
+ * Unit used for field coordinates and dimensions.
+ * Values are converted to pixels (96 DPI, what the API expects) before upload,
+ * so layouts from print designs can be passed through without manual 72-DPI math.
+ */
+export type CoordinateUnit = 'pixels' | 'points' | 'millimeters' | 'inches';
+
 export type SignatureFieldType =
   | 'signature'
   | 'initial'
@@ -13,7 +29,16 @@ export type SignatureFieldType =
   | 'first_name'
   | 'last_name'
   | 'email'
-  | 'checkbox';
+  | 'checkbox'
+  | 'dropdown';
+
+/**
+ * What completing the document means. 'signature' (the default) requires
+ * each recipient to sign; 'form' collects typed data only — documents hold
+ * just text/checkbox/dropdown fields and complete once every required field
+ * is submitted, with no signature ceremony.
+ */
+export type DocumentMode = 'signature' | 'form';
 
 // ============================================
 // RESPONSE TYPES
@@ -53,6 +78,19 @@ export interface VoidDocumentResponse {
   voidedAt?: string;
 }
 
+export interface CloneDocumentResponse {
+  /** Whether the request was successful */
+  success: boolean;
+  /** ID of the newly created document */
+  documentId: string;
+  /** ID of the source document that was cloned */
+  sourceDocumentId: string;
+  /** Status of the cloned document */
+  status: string;
+  /** Response message */
+  message: string;
+}
+
 export interface ResendEmailResponse {
   /** Whether the resend was successful */
   success: boolean;
@@ -108,9 +146,285 @@ export interface AuditTrailResponse {
   auditTrail: AuditTrailEntry[];
 }
 
+/** Lineage from a signing document back to the artifact that generated it */
+export interface GeneratedFrom {
+  /** TurboSign template the document was created from */
+  templateId?: string;
+  /** TurboDocx deliverable the document was created from */
+  deliverableId?: string;
+}
+
 export interface DocumentStatusResponse {
   /** Current document status */
   status: string;
+  /**
+   * Monotonic document version, bumped on every state change (signing,
+   * recipient updates, voiding). Pass it back as expectedVersion on
+   * mutations for optimistic concurrency.
+   */
+  version?: number;
+  /** Generation lineage, when the document came from a template or deliverable */
+  generatedFrom?: GeneratedFrom;
+}
+
+/** Options for void - optimistic concurrency precondition and pre-check control */
+export interface VoidDocumentOptions {
+  /**
+   * Only void if the document is still at this version (from getStatus).
+   * A mismatch — the document changed since it was read, e.g. a signer
+   * just completed it — fails with ConflictError instead of voiding.
+   */
+  expectedVersion?: number;
+  /**
+   * Skip the client-side terminal-status pre-check and send the void
+   * request regardless, leaving the server as the final authority.
+   */
+  force?: boolean;
+}
+
+/** Delivery state of a signature request email */
+export type EmailDeliveryState =
+  | 'pending'
+  | 'delivered'
+  | 'bounced'
+  | 'spam_flagged';
+
+export interface RecipientEmailStatus {
+  /** Recipient ID */
+  recipientId: string;
+  /** Recipient email address */
+  email: string;
+  /** Current delivery state */
+  state: EmailDeliveryState;
+  /** ISO 8601 timestamp of the last delivery event */
+  lastEventAt?: string;
+  /** Provider detail for bounces/spam flags */
+  detail?: string;
+}
+
+export interface EmailStatusResponse {
+  /** Document ID */
+  documentId: string;
+  /** Per-recipient email delivery status */
+  recipients: RecipientEmailStatus[];
+}
+
+export interface ArchiveDocumentResponse {
+  /** Document ID */
+  id: string;
+  /** Document name */
+  name: string;
+  /** Document status */
+  status: string;
+  /** Whether the document is archived */
+  archived: boolean;
+}
+
+/**
+ * Options for listDocuments - pagination and filtering
+ */
+export interface ListDocumentsOptions {
+  /** Number of results per page */
+  limit?: number;
+  /** Number of results to skip for pagination */
+  offset?: number;
+  /** Filter by document status */
+  status?: string;
+  /** Filter by archived state. Omit to list both archived and active documents. */
+  archived?: boolean;
+}
+
+export interface IterateDocumentsOptions {
+  /** Number of records fetched per request (default 100) */
+  pageSize?: number;
+  /** Number of records to skip before the first yielded record */
+  offset?: number;
+  /** Filter by document status */
+  status?: string;
+  /** Filter by archived state */
+  archived?: boolean;
+  /** Opaque token from a previous run's onResumeToken — takes precedence over offset */
+  resumeToken?: string;
+  /** Called after each yielded record with a token that resumes from the next one */
+  onResumeToken?: (token: string) => void;
+}
+
+/** Verification state of a sender address */
+export type SenderVerificationStatus = 'verified' | 'pending' | 'unverified';
+
+export interface VerifiedSender {
+  /** Sender email address */
+  email: string;
+  /** Current verification state */
+  status: SenderVerificationStatus;
+  /** ISO 8601 timestamp of when verification completed */
+  verifiedAt?: string;
+}
+
+export interface ListVerifiedSendersResponse {
+  /** Verified and pending sender addresses for the org */
+  results: VerifiedSender[];
+}
+
+export interface VerifySenderResponse {
+  /** Sender email address */
+  email: string;
+  /** State after the verification request */
+  status: SenderVerificationStatus;
+}
+
+/** Shape of a planned batch, for cost estimation before execution */
+export interface CostEstimateRequest {
+  /** Number of envelopes (documents) the batch would send */
+  envelopeCount: number;
+  /** Pages per document, when known */
+  pagesPerDocument?: number;
+  /** Recipients per document, when known */
+  recipientsPerDocument?: number;
+  /** Signature level the batch would use (affects per-envelope cost) */
+  signatureLevel?: string;
+}
+
+export interface CostEstimateResponse {
+  /** Envelopes the batch would consume */
+  envelopeCount: number;
+  /** Total page count across the batch, when derivable */
+  estimatedPageCount?: number;
+  /** Credits the batch would consume */
+  estimatedCredits: number;
+  /** Credits left on the org's plan */
+  creditsRemaining?: number;
+  /** Whether the batch fits within the current plan limits */
+  withinPlanLimits: boolean;
+}
+
+/** Value types an org-defined custom document property can take */
+export type CustomFieldType = 'string' | 'number' | 'boolean' | 'date' | 'enum';
+
+/** One entry of the org-defined custom document property schema */
+export interface CustomFieldDefinition {
+  /** Property key used in customFields on send */
+  key: string;
+  /** Display label shown in the TurboDocx UI */
+  label?: string;
+  /** Value type */
+  type: CustomFieldType;
+  /** Whether every document must carry a value for this property */
+  required?: boolean;
+  /** Allowed values, for enum-typed properties */
+  allowedValues?: string[];
+}
+
+export interface CustomFieldDefinitionsResponse {
+  /** The org's custom document property schema */
+  results: CustomFieldDefinition[];
+}
+
+/** Org-defined custom document property values set on send */
+export type CustomFieldValues = Record<string, string | number | boolean>;
+
+/** Quota position of a planned batch relative to the org's remaining credits */
+export type QuotaStatus = 'ok' | 'low' | 'exceeded';
+
+export interface QuotaCheck {
+  /** Whether the batch fits, and how comfortably */
+  status: QuotaStatus;
+  /** Credits the batch would consume */
+  estimatedCredits: number;
+  /** Credits left on the org's plan before the batch */
+  creditsRemaining?: number;
+  /** Credits that would remain after the batch, when derivable */
+  creditsAfter?: number;
+}
+
+/** Recipient action that can be simulated in sandbox mode */
+export type RecipientAction = 'view' | 'sign' | 'decline';
+
+export interface SimulateRecipientActionResponse {
+  /** Document ID */
+  documentId: string;
+  /** Recipient the action was applied to */
+  recipientId: string;
+  /** Action that was simulated */
+  action: RecipientAction;
+  /** Document status after the simulated action */
+  status: string;
+}
+
+/**
+ * Statuses after which a document can no longer change state. Mirrors the
+ * empty rows of DOCUMENT_TRANSITIONS in utils/lifecycle — prefer the
+ * isTerminalDocumentStatus helper for new code.
+ */
+export const TERMINAL_DOCUMENT_STATUSES = ['completed', 'voided', 'declined', 'expired'] as const;
+
+/**
+ * Options for watch - polling cadence and stop behavior
+ */
+export interface WatchOptions {
+  /** Poll interval while documents are changing, in ms (default 5000) */
+  pollIntervalMs?: number;
+  /** Upper bound the interval backs off to while nothing changes, in ms (default 60000) */
+  maxPollIntervalMs?: number;
+  /** Stop watching a document once it reaches a terminal status (default true) */
+  stopAtTerminal?: boolean;
+}
+
+/**
+ * A single status transition observed by watch. The first emission for a
+ * document reports its initial status with no previousStatus.
+ */
+export interface DocumentStatusChange {
+  /** Document ID */
+  documentId: string;
+  /** Status the document moved to */
+  status: string;
+  /** Status the document moved from, if previously observed */
+  previousStatus?: string;
+}
+
+export interface DocumentListItem {
+  /** Document ID */
+  id: string;
+  /** Document name */
+  name: string;
+  /** Document status */
+  status: string;
+  /** Whether the document is archived */
+  archived?: boolean;
+  /** ISO 8601 creation timestamp */
+  createdOn?: string;
+  /** ISO 8601 last update timestamp */
+  updatedOn?: string;
+  /** Generation lineage, when the document came from a template or deliverable */
+  generatedFrom?: GeneratedFrom;
+}
+
+export interface ExpiringDocument {
+  /** Document ID */
+  id: string;
+  /** Document name */
+  name: string;
+  /** Document status */
+  status: string;
+  /** ISO 8601 expiration timestamp */
+  expiresOn: string;
+  /** Whole days until the document expires */
+  daysRemaining: number;
+}
+
+export interface ExpiringDocumentsResponse {
+  /** Documents expiring within the requested window, soonest first */
+  results: ExpiringDocument[];
+  /** Total number of documents expiring within the window */
+  totalRecords: number;
+}
+
+export interface DocumentListResponse {
+  /** Array of documents */
+  results: DocumentListItem[];
+  /** Total number of documents matching the query */
+  totalRecords: number;
 }
 
 // ============================================
@@ -136,6 +450,8 @@ export interface Field {
   height?: number;
   /** Recipient email - which recipient fills this field */
   recipientEmail: string;
+  /** Tab order for the signer (1-indexed). Must be unique per recipient. Fields without a tabIndex are visited in document order after indexed fields. */
+  tabIndex?: number;
   /** Default value for the field (for checkbox: "true" or "false") */
   defaultValue?: string;
   /** Whether this is a multiline text field */
@@ -146,6 +462,8 @@ export interface Field {
   required?: boolean;
   /** Background color (hex, rgb, or named colors) */
   backgroundColor?: string;
+  /** Screen-reader hint announced for this field during the signing ceremony (falls back to the field type) */
+  ariaLabel?: string;
   /** Template anchor configuration for dynamic positioning */
   template?: {
     /** Text anchor pattern like {TagName} */
@@ -165,9 +483,39 @@ export interface Field {
   };
 }
 
+/**
+ * E-signature assurance level (eIDAS terminology).
+ * - 'simple': standard electronic signature (default)
+ * - 'advanced': AES - uniquely linked to and identifying the signer
+ * - 'qualified': QES - advanced signature backed by a qualified certificate
+ */
+export type SignatureLevel = 'simple' | 'advanced' | 'qualified';
+
+/**
+ * URLs the signer is returned to after the signing ceremony
+ */
+export interface RedirectUrls {
+  /** URL to redirect to after the recipient completes signing */
+  onComplete?: string;
+  /** URL to redirect to after the recipient declines */
+  onDecline?: string;
+}
+
 /**
  * Recipient configuration for single-step operations
  */
+/**
+ * Accessibility options for the signing ceremony, for meeting public-sector
+ * accessibility requirements via the API. Per-field screen-reader hints are
+ * set via Field.ariaLabel.
+ */
+export interface AccessibilityOptions {
+  /** Render the signing ceremony with enlarged text and controls */
+  largeTextMode?: boolean;
+  /** Announce field descriptions to screen readers even when ariaLabel is unset */
+  screenReaderHints?: boolean;
+}
+
 export interface Recipient {
   /** Recipient's full name */
   name: string;
@@ -175,6 +523,22 @@ export interface Recipient {
   email: string;
   /** Signing order (1-indexed) */
   signingOrder: number;
+  /** Per-recipient redirect URL overrides (take precedence over the request-level redirectUrls) */
+  redirectUrls?: RedirectUrls;
+  /** Per-recipient signature level override (takes precedence over the request-level signatureLevel) */
+  signatureLevel?: SignatureLevel;
+  /** Per-recipient IANA time zone override for auto-filled Date fields (takes precedence over the request-level timeZone) */
+  timeZone?: string;
+}
+
+/**
+ * Options for cloneDocument - overrides applied to the cloned document
+ */
+export interface CloneDocumentOptions {
+  /** Replace the original recipients on the cloned document */
+  newRecipients?: Recipient[];
+  /** Name for the cloned document (defaults to the original name) */
+  documentName?: string;
 }
 
 /**
@@ -193,8 +557,10 @@ export interface CreateSignatureReviewLinkRequest {
   templateId?: string;
   /** Recipients who will sign */
   recipients: Recipient[];
-  /** Signature fields configuration */
-  fields: Field[];
+  /** Signature fields configuration (required unless fieldLayoutId is provided) */
+  fields?: Field[];
+  /** ID of a field layout previously saved with saveFieldLayout (alternative to fields) */
+  fieldLayoutId?: string;
   /** Document name */
   documentName?: string;
   /** Document description */
@@ -205,6 +571,24 @@ export interface CreateSignatureReviewLinkRequest {
   senderEmail?: string;
   /** CC emails (comma-separated or array) */
   ccEmails?: string | string[];
+  /** Origin for field coordinates (default 'topLeft'). When 'bottomLeft', pageHeight is required and y values are converted before upload. */
+  coordinateSystem?: CoordinateSystem;
+  /** Page height in the same unit as field coordinates (required when coordinateSystem is 'bottomLeft') */
+  pageHeight?: number;
+  /** Unit for field coordinates and dimensions (default 'pixels'). Converted before upload. */
+  unit?: CoordinateUnit;
+  /** URLs signers are returned to after signing or declining (per-recipient overrides on Recipient take precedence) */
+  redirectUrls?: RedirectUrls;
+  /** Signature assurance level for all recipients (default 'simple'; per-recipient overrides on Recipient take precedence) */
+  signatureLevel?: SignatureLevel;
+  /** IANA time zone auto-filled Date fields render in, e.g. 'America/New_York' (default is the org setting; per-recipient overrides on Recipient take precedence) */
+  timeZone?: string;
+  /** Accessibility options for the signing ceremony */
+  accessibility?: AccessibilityOptions;
+  /** Org-defined custom document property values, validated client-side against getCustomFieldDefinitions */
+  customFields?: CustomFieldValues;
+  /** Completion mode (default 'signature'). In 'form' mode, no signature fields are placed and completion only requires every required field to be submitted. */
+  mode?: DocumentMode;
 }
 
 /**
@@ -241,8 +625,68 @@ export interface SendSignatureRequest {
   templateId?: string;
   /** Recipients who will sign */
   recipients: Recipient[];
-  /** Signature fields configuration */
-  fields: Field[];
+  /** Signature fields configuration (required unless fieldLayoutId is provided) */
+  fields?: Field[];
+  /** ID of a field layout previously saved with saveFieldLayout (alternative to fields) */
+  fieldLayoutId?: string;
+  /** Document name */
+  documentName?: string;
+  /** Document description */
+  documentDescription?: string;
+  /** Sender name */
+  senderName?: string;
+  /** Sender email */
+  senderEmail?: string;
+  /** CC emails (comma-separated or array) */
+  ccEmails?: string | string[];
+  /** Origin for field coordinates (default 'topLeft'). When 'bottomLeft', pageHeight is required and y values are converted before upload. */
+  coordinateSystem?: CoordinateSystem;
+  /** Page height in the same unit as field coordinates (required when coordinateSystem is 'bottomLeft') */
+  pageHeight?: number;
+  /** Unit for field coordinates and dimensions (default 'pixels'). Converted before upload. */
+  unit?: CoordinateUnit;
+  /** URLs signers are returned to after signing or declining (per-recipient overrides on Recipient take precedence) */
+  redirectUrls?: RedirectUrls;
+  /** Signature assurance level for all recipients (default 'simple'; per-recipient overrides on Recipient take precedence) */
+  signatureLevel?: SignatureLevel;
+  /** IANA time zone auto-filled Date fields render in, e.g. 'America/New_York' (default is the org setting; per-recipient overrides on Recipient take precedence) */
+  timeZone?: string;
+  /** Accessibility options for the signing ceremony */
+  accessibility?: AccessibilityOptions;
+  /** Org-defined custom document property values, validated client-side against getCustomFieldDefinitions */
+  customFields?: CustomFieldValues;
+  /** Completion mode (default 'signature'). In 'form' mode, no signature fields are placed and completion only requires every required field to be submitted. */
+  mode?: DocumentMode;
+}
+
+/**
+ * Status values for acknowledgment-only documents. Distinct from signature
+ * document statuses: recipients confirm receipt rather than signing.
+ */
+export type AcknowledgmentStatus =
+  | 'pending_acknowledgment'
+  | 'partially_acknowledged'
+  | 'acknowledged'
+  | 'voided';
+
+/**
+ * Request for sendForAcknowledgment - e-delivery receipt without a
+ * signature ceremony. No fields are placed on the document; recipients just
+ * confirm they received it.
+ */
+export interface SendForAcknowledgmentRequest {
+  /** PDF file as file path, Buffer, or browser File */
+  file?: string | File | Buffer;
+  /** Original filename (used when file is a Buffer) */
+  fileName?: string;
+  /** URL to document file */
+  fileLink?: string;
+  /** TurboDocx deliverable ID */
+  deliverableId?: string;
+  /** TurboDocx template ID */
+  templateId?: string;
+  /** Recipients who will acknowledge receipt */
+  recipients: Recipient[];
   /** Document name */
   documentName?: string;
   /** Document description */
@@ -253,6 +697,66 @@ export interface SendSignatureRequest {
   senderEmail?: string;
   /** CC emails (comma-separated or array) */
   ccEmails?: string | string[];
+  /** URLs recipients are returned to after acknowledging (per-recipient overrides on Recipient take precedence) */
+  redirectUrls?: RedirectUrls;
+  /** IANA time zone acknowledgment timestamps render in, e.g. 'America/New_York' (default is the org setting) */
+  timeZone?: string;
+  /** Org-defined custom document property values, validated client-side against getCustomFieldDefinitions */
+  customFields?: CustomFieldValues;
+}
+
+/**
+ * Response from sendForAcknowledgment
+ */
+export interface SendForAcknowledgmentResponse {
+  /** Whether the request was successful */
+  success: boolean;
+  /** Document ID */
+  documentId: string;
+  /** Acknowledgment status — starts at 'pending_acknowledgment' */
+  status: AcknowledgmentStatus;
+  /** Recipients with their metadata */
+  recipients?: ReviewRecipient[];
+  /** Response message */
+  message: string;
+}
+
+/**
+ * Response from saveFieldLayout
+ */
+export interface SaveFieldLayoutResponse {
+  /** ID of the saved layout, usable as fieldLayoutId on send requests */
+  layoutId: string;
+  /** Template the layout is saved against */
+  templateId: string;
+  /** Number of fields in the layout */
+  fieldCount: number;
+  /** Response message */
+  message: string;
+}
+
+/**
+ * Response from getOriginalRequest - the send request as it was submitted
+ */
+export interface OriginalRequestResponse {
+  /** Document ID */
+  documentId: string;
+  /** Recipients as submitted */
+  recipients: Recipient[];
+  /** Fields as submitted (after unit/coordinate conversion) */
+  fields: Field[];
+  /** Document name as submitted */
+  documentName?: string;
+  /** Document description as submitted */
+  documentDescription?: string;
+  /** Sender email as submitted */
+  senderEmail?: string;
+  /** Sender name as submitted */
+  senderName?: string;
+  /** CC emails as submitted */
+  ccEmails?: string[];
+  /** ISO 8601 timestamp of the original submission */
+  submittedOn?: string;
 }
 
 /**
@@ -270,3 +774,134 @@ export interface SendSignatureResponse {
   /** Response message */
   message: string;
 }
+
+/**
+ * Options for sendBulkFromCsv - one single-signer envelope per CSV row
+ */
+export interface BulkSendFromCsvOptions {
+  /** Template every envelope is generated from */
+  templateId: string;
+  /** Saved field layout applied to every envelope (see saveFieldLayout) */
+  fieldLayoutId: string;
+  /** Document name; '{{column}}' placeholders are filled from the row, e.g. 'Offer letter - {{name}}' */
+  documentName?: string;
+  /** Sender email override */
+  senderEmail?: string;
+  /** Sender name override */
+  senderName?: string;
+  /** Delay between envelope sends in milliseconds (default 500) — keeps large batches under API rate limits */
+  pacingMs?: number;
+  /** Token-bucket pacer used between sends in place of the fixed pacingMs delay, for burst absorption and jitter */
+  pacer?: Pacer;
+}
+
+/**
+ * Outcome of one CSV row in a bulk send
+ */
+export interface BulkRowResult {
+  /** Data row number (1-indexed, excluding the header) */
+  row: number;
+  /** Recipient email from the row, when present */
+  email?: string;
+  /** Whether the envelope was sent */
+  status: 'sent' | 'failed';
+  /** Document ID of the sent envelope */
+  documentId?: string;
+  /** What went wrong, for failed rows */
+  error?: string;
+}
+
+/**
+ * Report from sendBulkFromCsv - every row accounted for, in order
+ */
+export interface BulkSendReport {
+  /** Number of data rows in the CSV */
+  total: number;
+  /** Rows that produced an envelope */
+  sent: number;
+  /** Rows that failed validation or sending */
+  failed: number;
+  /** Per-row outcomes, in CSV order */
+  results: BulkRowResult[];
+}
+
+/**
+ * Lifecycle states of a server-side bulk job
+ */
+export type BulkJobStatus = 'queued' | 'processing' | 'completed' | 'cancelled' | 'failed';
+
+/**
+ * Progress counters for a server-side bulk job
+ */
+export interface BulkJobProgress {
+  /** Envelopes still waiting to be sent */
+  queued: number;
+  /** Envelopes sent so far */
+  sent: number;
+  /** Envelopes that failed */
+  failed: number;
+}
+
+/**
+ * A server-side bulk job, as returned by getBulkJob/listBulkJobs
+ */
+export interface BulkJob {
+  /** Job ID */
+  id: string;
+  /** Current lifecycle state */
+  status: BulkJobStatus;
+  /** Per-envelope progress counters */
+  progress: BulkJobProgress;
+  /** Total number of envelopes in the job */
+  total: number;
+  /** ISO 8601 timestamp the job was queued */
+  createdOn?: string;
+  /** ISO 8601 timestamp the job finished, for terminal states */
+  completedOn?: string;
+}
+
+export interface ListBulkJobsOptions {
+  /** Number of results per page */
+  limit?: number;
+  /** Number of results to skip for pagination */
+  offset?: number;
+  /** Filter by job status */
+  status?: BulkJobStatus;
+}
+
+export interface BulkJobListResponse {
+  /** Array of bulk jobs */
+  results: BulkJob[];
+  /** Total number of jobs matching the query */
+  totalRecords: number;
+}
+
+/**
+ * A single submitted field value, as returned by getFieldValues
+ */
+export interface FieldValue {
+  /** ID of the field */
+  fieldId: string;
+  /** Field type */
+  type: SignatureFieldType;
+  /** Recipient the field was bound to */
+  recipientEmail: string;
+  /** Field label, when one was set */
+  label?: string;
+  /** Submitted value — checkbox fields carry booleans, everything else strings; null when not yet submitted */
+  value: string | boolean | null;
+  /** ISO 8601 timestamp of submission */
+  submittedOn?: string;
+}
+
+/**
+ * Response from getFieldValues - the data collected by a document's fields
+ */
+export interface FieldValuesResponse {
+  /** Document ID */
+  documentId: string;
+  /** True once every required field has been submitted */
+  complete: boolean;
+  /** One entry per field on the document */
+  results: FieldValue[];
+}
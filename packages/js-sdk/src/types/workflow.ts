@@ -0,0 +1,71 @@
+/**
+ * Workflow types - declarative generate-and-send specs
+ */
+
+import type { DeliverableVariable } from './deliverable';
+import type { Field, Recipient } from './sign';
+
+/**
+ * Generate step: render a deliverable from a template. Variables can be
+ * given inline or loaded from a JSON file at run time (Node only), so the
+ * same spec can be replayed against fresh data.
+ */
+export interface WorkflowGenerateStep {
+  /** Template ID to generate from */
+  templateId: string;
+  /** Name for the generated deliverable */
+  name: string;
+  /** Inline variables for substitution */
+  variables?: DeliverableVariable[];
+  /** Path to a JSON file holding the variables array (merged after inline ones) */
+  variablesFile?: string;
+  /** Tags to associate with the deliverable */
+  tags?: string[];
+}
+
+/**
+ * Sign step: send the generated deliverable for signature. Omit it for a
+ * generate-only workflow.
+ */
+export interface WorkflowSignStep {
+  /** Signers, in signing order */
+  recipients: Recipient[];
+  /** Saved field layout to apply */
+  fieldLayoutId?: string;
+  /** Inline signature fields (alternative to fieldLayoutId) */
+  fields?: Field[];
+  /** Name shown to recipients (defaults to the deliverable name) */
+  documentName?: string;
+  /** CC addresses for completion emails */
+  ccEmails?: string[];
+}
+
+/**
+ * A declarative workflow: generate a document, then (optionally) send it
+ * for signature. Specs are plain JSON, so ops teams can keep them in
+ * config repos and run them without writing code.
+ */
+export interface WorkflowSpec {
+  /** Generation step (always first) */
+  generate: WorkflowGenerateStep;
+  /** Signature step, run against the generated deliverable */
+  sign?: WorkflowSignStep;
+}
+
+/** One executed step and the ID it produced */
+export interface WorkflowStepResult {
+  /** Which step ran */
+  step: 'generate' | 'sign';
+  /** ID the step produced (deliverable ID or document ID) */
+  id: string;
+}
+
+/** Outcome of a workflow run, with every step's ID recorded */
+export interface WorkflowResult {
+  /** ID of the generated deliverable */
+  deliverableId: string;
+  /** ID of the signature document, when a sign step ran */
+  documentId?: string;
+  /** Executed steps in order, for audit logs */
+  steps: WorkflowStepResult[];
+}
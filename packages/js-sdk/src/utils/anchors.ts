@@ -0,0 +1,159 @@
+/**
+ * Template anchor builder for TurboSign fields
+ *
+ * Builds the `template` configuration on a Field with local validation,
+ * so bad regex patterns fail fast in the SDK instead of server-side.
+ */
+
+import { Field } from '../types/sign';
+import { ValidationError } from './errors';
+
+type TemplateConfig = NonNullable<Field['template']>;
+
+/** Maximum accepted regex pattern length */
+const MAX_PATTERN_LENGTH = 1000;
+
+/**
+ * Heuristic for catastrophic backtracking: a quantifier applied to a group
+ * that itself contains an unbounded quantifier, e.g. (a+)+ or (\w*)* .
+ */
+const NESTED_QUANTIFIER = /\((?:[^()\\]|\\.)*[+*](?:[^()\\]|\\.)*\)[+*]/;
+
+/**
+ * Anchor-based positioning builder.
+ *
+ * Instances are structurally compatible with `Field['template']`, so they can
+ * be assigned directly to a field:
+ *
+ * ```typescript
+ * const field: Field = {
+ *   type: 'signature',
+ *   recipientEmail: 'john@example.com',
+ *   template: TemplateAnchor.literal('Sign here:'),
+ * };
+ * ```
+ */
+export class TemplateAnchor implements TemplateConfig {
+  anchor?: string;
+  searchText?: string;
+  placement?: TemplateConfig['placement'];
+  size?: TemplateConfig['size'];
+  offset?: TemplateConfig['offset'];
+  caseSensitive?: boolean;
+  useRegex?: boolean;
+
+  private constructor(init: TemplateConfig) {
+    Object.assign(this, init);
+  }
+
+  /**
+   * Anchor on a literal text match (no regex interpretation).
+   *
+   * @param text - Exact text to search for in the document
+   */
+  static literal(text: string): TemplateAnchor {
+    if (!text) {
+      throw new ValidationError('Anchor text must not be empty.');
+    }
+    return new TemplateAnchor({ searchText: text, useRegex: false });
+  }
+
+  /**
+   * Anchor on a regex pattern, validated locally.
+   *
+   * The pattern is compiled with the JavaScript regex engine and rejected if
+   * it does not parse or contains constructs prone to catastrophic
+   * backtracking (nested unbounded quantifiers), instead of failing
+   * server-side after the document has been uploaded.
+   *
+   * @param pattern - Regex pattern to search for in the document
+   * @throws ValidationError if the pattern is invalid or unsafe
+   */
+  static regex(pattern: string): TemplateAnchor {
+    if (!pattern) {
+      throw new ValidationError('Anchor pattern must not be empty.');
+    }
+    if (pattern.length > MAX_PATTERN_LENGTH) {
+      throw new ValidationError(
+        `Anchor pattern exceeds ${MAX_PATTERN_LENGTH} characters.`
+      );
+    }
+
+    try {
+      new RegExp(pattern);
+    } catch (error) {
+      throw new ValidationError(`Invalid anchor regex pattern: ${error}`);
+    }
+
+    if (NESTED_QUANTIFIER.test(pattern)) {
+      throw new ValidationError(
+        'Anchor regex pattern contains nested unbounded quantifiers (e.g. "(a+)+"), which can cause catastrophic backtracking. Rewrite the pattern without nesting quantifiers.'
+      );
+    }
+
+    return new TemplateAnchor({ searchText: pattern, useRegex: true });
+  }
+
+  /**
+   * Anchor on a placeholder tag like {SignatureTag}.
+   *
+   * @param anchor - Tag text, braces included
+   */
+  static tag(anchor: string): TemplateAnchor {
+    if (!anchor) {
+      throw new ValidationError('Anchor tag must not be empty.');
+    }
+    return new TemplateAnchor({ anchor });
+  }
+
+  /**
+   * Escape text so it matches literally inside a regex pattern.
+   */
+  static escape(text: string): string {
+    return text.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+  }
+
+  // ============================================
+  // CHAINABLE CUSTOMIZATION
+  // ============================================
+
+  /**
+   * Set where the field is placed relative to the anchor text.
+   */
+  withPlacement(placement: NonNullable<TemplateConfig['placement']>): this {
+    this.placement = placement;
+    return this;
+  }
+
+  /**
+   * Set the offset from the anchor position.
+   */
+  withOffset(x: number, y: number): this {
+    this.offset = { x, y };
+    return this;
+  }
+
+  /**
+   * Set the size of the placed field.
+   */
+  withSize(width: number, height: number): this {
+    this.size = { width, height };
+    return this;
+  }
+
+  /**
+   * Match the anchor text case-insensitively (the API default).
+   */
+  caseInsensitive(): this {
+    this.caseSensitive = false;
+    return this;
+  }
+
+  /**
+   * Match the anchor text case-sensitively.
+   */
+  withCaseSensitive(): this {
+    this.caseSensitive = true;
+    return this;
+  }
+}
@@ -0,0 +1,94 @@
+/**
+ * Client-side circuit breaker for the HTTP client
+ *
+ * When the API is degraded, batch jobs can hammer it with thousands of
+ * doomed requests. The breaker tracks consecutive transient failures and,
+ * once a threshold is crossed, fails fast with CircuitOpenError instead of
+ * sending more traffic. After the open duration a limited number of probe
+ * requests are let through; one success closes the circuit again.
+ */
+
+import { CircuitOpenError } from './errors';
+
+export interface CircuitBreakerOptions {
+  /** Consecutive transient failures before the circuit opens. Defaults to 5. */
+  failureThreshold?: number;
+  /** How long the circuit stays open before probing again, in ms. Defaults to 30000. */
+  openDurationMs?: number;
+  /** Concurrent probe requests allowed while half-open. Defaults to 1. */
+  halfOpenProbes?: number;
+}
+
+type CircuitState = 'closed' | 'open' | 'half-open';
+
+export class CircuitBreaker {
+  private readonly failureThreshold: number;
+  private readonly openDurationMs: number;
+  private readonly halfOpenProbes: number;
+
+  private state: CircuitState = 'closed';
+  private consecutiveFailures = 0;
+  private openedAt = 0;
+  private probesInFlight = 0;
+
+  constructor(options: CircuitBreakerOptions = {}) {
+    this.failureThreshold = options.failureThreshold ?? 5;
+    this.openDurationMs = options.openDurationMs ?? 30000;
+    this.halfOpenProbes = options.halfOpenProbes ?? 1;
+  }
+
+  /**
+   * Gate a request attempt. Throws CircuitOpenError while the circuit is
+   * open; while half-open, admits up to halfOpenProbes concurrent probes.
+   */
+  checkAllowed(): void {
+    if (this.state === 'open') {
+      if (Date.now() - this.openedAt < this.openDurationMs) {
+        const remainingMs = this.openDurationMs - (Date.now() - this.openedAt);
+        throw new CircuitOpenError(
+          `Circuit breaker is open after ${this.consecutiveFailures} consecutive failures; retry in ${remainingMs}ms`
+        );
+      }
+      this.state = 'half-open';
+      this.probesInFlight = 0;
+    }
+
+    if (this.state === 'half-open') {
+      if (this.probesInFlight >= this.halfOpenProbes) {
+        throw new CircuitOpenError('Circuit breaker is half-open and all probe slots are in use');
+      }
+      this.probesInFlight++;
+    }
+  }
+
+  /** Record a successful attempt: closes the circuit and resets counters. */
+  recordSuccess(): void {
+    if (this.state === 'half-open') {
+      this.probesInFlight--;
+    }
+    this.state = 'closed';
+    this.consecutiveFailures = 0;
+  }
+
+  /**
+   * Record a transient failure. Opens the circuit once the threshold is
+   * crossed, or immediately when a half-open probe fails.
+   */
+  recordFailure(): void {
+    if (this.state === 'half-open') {
+      this.probesInFlight--;
+      this.open();
+      return;
+    }
+
+    this.consecutiveFailures++;
+    if (this.consecutiveFailures >= this.failureThreshold) {
+      this.open();
+    }
+  }
+
+  private open(): void {
+    this.state = 'open';
+    this.openedAt = Date.now();
+  }
+}
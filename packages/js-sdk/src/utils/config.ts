@@ -0,0 +1,114 @@
+/**
+ * Layered configuration loader
+ *
+ * Assembles an HttpClientConfig from three layers, lowest precedence first:
+ * a JSON config file, TURBODOCX_* environment variables, then programmatic
+ * overrides. Ops can manage credentials per environment in a file or env
+ * vars without code changes, while code-level settings still win.
+ */
+
+import { HttpClientConfig } from '../http';
+import { ValidationError } from './errors';
+import { envVar, isNode, requireFs } from './runtime';
+
+/** Default config file name, resolved relative to the working directory */
+const DEFAULT_CONFIG_FILE = 'turbodocx.config.json';
+
+/**
+ * Keys the config file may set. A subset of HttpClientConfig — everything
+ * representable as plain JSON (no callbacks, dispatchers, or middleware).
+ */
+export interface ConfigFile {
+  apiKey?: string;
+  accessToken?: string;
+  baseUrl?: string;
+  orgId?: string;
+  senderEmail?: string;
+  senderName?: string;
+  timeoutMs?: number;
+  maxAttempts?: number;
+  idempotencyKeys?: boolean;
+  proxyUrl?: string;
+  debugLogging?: boolean;
+  defaultHeaders?: Record<string, string>;
+}
+
+/** Drop undefined values so a layer only overrides what it actually sets */
+const defined = <T extends object>(layer: T): Partial<T> => {
+  const result: Partial<T> = {};
+  for (const [key, value] of Object.entries(layer)) {
+    if (value !== undefined) {
+      (result as Record<string, unknown>)[key] = value;
+    }
+  }
+  return result;
+};
+
+/**
+ * Read and parse the config file for loadConfig
+ *
+ * The path comes from the TURBODOCX_CONFIG environment variable, falling
+ * back to ./turbodocx.config.json. An explicitly-configured path that does
+ * not exist is an error; the implicit default is skipped silently. Outside
+ * Node there is no filesystem, so the file layer is always empty.
+ */
+function readConfigFile(): ConfigFile {
+  if (!isNode()) {
+    return {};
+  }
+
+  const explicitPath = envVar('TURBODOCX_CONFIG');
+  const path = explicitPath ?? DEFAULT_CONFIG_FILE;
+  const fs = requireFs();
+
+  if (!fs.existsSync(path)) {
+    if (explicitPath) {
+      throw new ValidationError(`Config file '${explicitPath}' (from TURBODOCX_CONFIG) does not exist.`);
+    }
+    return {};
+  }
+
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(fs.readFileSync(path, 'utf8'));
+  } catch (error) {
+    throw new ValidationError(
+      `Config file '${path}' is not valid JSON: ${error instanceof Error ? error.message : error}`
+    );
+  }
+  if (!parsed || typeof parsed !== 'object' || Array.isArray(parsed)) {
+    throw new ValidationError(`Config file '${path}' must contain a JSON object.`);
+  }
+  return parsed as ConfigFile;
+}
+
+/**
+ * Load configuration from file, environment, and code
+ *
+ * Precedence, lowest first: the JSON config file (TURBODOCX_CONFIG path or
+ * ./turbodocx.config.json), TURBODOCX_* environment variables, then the
+ * overrides argument. Pass the result to configure() or an instance client.
+ *
+ * @param overrides - Programmatic settings that win over file and env
+ * @returns Merged HttpClientConfig
+ *
+ * @example
+ * ```typescript
+ * TurboSign.configure(loadConfig({ timeoutMs: 30000 }));
+ * ```
+ */
+export function loadConfig(overrides: Partial<HttpClientConfig> = {}): HttpClientConfig {
+  const fromEnv: Partial<HttpClientConfig> = {
+    apiKey: envVar('TURBODOCX_API_KEY'),
+    orgId: envVar('TURBODOCX_ORG_ID'),
+    senderEmail: envVar('TURBODOCX_SENDER_EMAIL'),
+    senderName: envVar('TURBODOCX_SENDER_NAME'),
+    baseUrl: envVar('TURBODOCX_BASE_URL'),
+  };
+
+  return {
+    ...defined(readConfigFile()),
+    ...defined(fromEnv),
+    ...defined(overrides),
+  };
+}
@@ -0,0 +1,92 @@
+/**
+ * Minimal CSV parsing for bulk sends
+ *
+ * Hand-rolled so the SDK keeps its zero-runtime-dependency policy. Handles
+ * quoted fields, escaped quotes (""), and CR/CRLF line endings — enough for
+ * the recipient spreadsheets sendBulkFromCsv consumes. Not a
+ * general-purpose CSV library.
+ */
+
+import { ValidationError } from './errors';
+
+/**
+ * Parse CSV text into rows of fields
+ *
+ * @param text - Raw CSV content
+ * @returns One string array per row
+ * @throws ValidationError for an unterminated quoted field
+ */
+export function parseCsv(text: string): string[][] {
+  const rows: string[][] = [];
+  let row: string[] = [];
+  let field = '';
+  let inQuotes = false;
+
+  for (let i = 0; i < text.length; i++) {
+    const ch = text[i];
+    if (inQuotes) {
+      if (ch === '"') {
+        if (text[i + 1] === '"') {
+          // Escaped quote inside a quoted field
+          field += '"';
+          i++;
+        } else {
+          inQuotes = false;
+        }
+      } else {
+        field += ch;
+      }
+    } else if (ch === '"') {
+      inQuotes = true;
+    } else if (ch === ',') {
+      row.push(field);
+      field = '';
+    } else if (ch === '\n' || ch === '\r') {
+      if (ch === '\r' && text[i + 1] === '\n') {
+        i++;
+      }
+      row.push(field);
+      field = '';
+      rows.push(row);
+      row = [];
+    } else {
+      field += ch;
+    }
+  }
+
+  if (inQuotes) {
+    throw new ValidationError('Malformed CSV: unterminated quoted field.');
+  }
+  if (field !== '' || row.length > 0) {
+    row.push(field);
+    rows.push(row);
+  }
+
+  return rows;
+}
+
+/**
+ * Parse CSV text using the first row as column headers
+ *
+ * Header names are trimmed; blank lines are skipped; rows shorter than the
+ * header get '' for their missing columns.
+ *
+ * @param text - Raw CSV content including the header row
+ * @returns One record per data row, keyed by header name
+ * @throws ValidationError when the input has no header row
+ */
+export function parseCsvRecords(text: string): Array<Record<string, string>> {
+  const rows = parseCsv(text).filter((row) => row.some((field) => field.trim() !== ''));
+  if (rows.length === 0) {
+    throw new ValidationError('CSV input is empty.');
+  }
+
+  const headers = rows[0].map((header) => header.trim());
+  return rows.slice(1).map((row) => {
+    const record: Record<string, string> = {};
+    headers.forEach((header, index) => {
+      record[header] = row[index] ?? '';
+    });
+    return record;
+  });
+}
@@ -2,9 +2,71 @@
  * Error classes for TurboDocx SDK
  */
 
+/**
+ * Stable machine-readable error codes, for branching on errors without
+ * substring-matching English messages.
+ *
+ * SDK-side codes are set on the error's `code` property by the SDK itself
+ * (config, transport, and policy failures). API-side codes arrive on the
+ * structured error body and are matched via `error.is(code)`. The enum is
+ * additive: new codes may appear in minor releases.
+ */
+export enum TurboDocxErrorCode {
+  // SDK-side
+  AuthenticationError = 'AUTHENTICATION_ERROR',
+  ValidationError = 'VALIDATION_ERROR',
+  NotFound = 'NOT_FOUND',
+  Conflict = 'CONFLICT',
+  InvalidState = 'INVALID_STATE',
+  RateLimitExceeded = 'RATE_LIMIT_EXCEEDED',
+  IntegrityError = 'INTEGRITY_ERROR',
+  NetworkError = 'NETWORK_ERROR',
+  Timeout = 'TIMEOUT',
+  Cancelled = 'CANCELLED',
+  CircuitOpen = 'CIRCUIT_OPEN',
+  QuotaExceeded = 'QUOTA_EXCEEDED',
+  QuotaLow = 'QUOTA_LOW',
+  ClientClosed = 'CLIENT_CLOSED',
+  // API-side, carried on the structured error body
+  TemplateNotFound = 'TEMPLATE_NOT_FOUND',
+  DeliverableNotFound = 'DELIVERABLE_NOT_FOUND',
+  DocumentNotFound = 'DOCUMENT_NOT_FOUND',
+  RecipientEmailInvalid = 'RECIPIENT_EMAIL_INVALID',
+  SenderNotVerified = 'SENDER_NOT_VERIFIED',
+  InvalidFileType = 'INVALID_FILE_TYPE',
+  DocumentAlreadyCompleted = 'DOCUMENT_ALREADY_COMPLETED',
+  DocumentVoided = 'DOCUMENT_VOIDED',
+}
+
+/**
+ * Structured error body returned by the API, when the response was
+ * parseable JSON. Carried on TurboDocxError.apiError so callers can branch
+ * on machine-readable codes instead of matching message strings.
+ */
+export interface ApiErrorBody {
+  /** Machine-readable error code from the API */
+  code?: string;
+  /** Human-readable error message */
+  message?: string;
+  /** Additional structured detail (e.g. per-field validation errors) */
+  details?: unknown;
+  /** Request ID for support tickets */
+  requestId?: string;
+}
+
 export class TurboDocxError extends Error {
   public readonly statusCode?: number;
   public readonly code?: string;
+  /** Structured error body from the API, when the response was parseable JSON */
+  public apiError?: ApiErrorBody;
+  /** Request ID from the x-request-id response header — quote this in support tickets */
+  public requestId?: string;
+  /** SDK operation the error surfaced from (e.g. 'TurboSign.getAuditTrail') */
+  public operation?: string;
+  /** Actionable hint for fixing the error (e.g. "did you forget senderEmail?") */
+  public help?: string;
+  /** Link to the relevant documentation page */
+  public docsUrl?: string;
 
   constructor(message: string, statusCode?: number, code?: string) {
     super(message);
@@ -15,39 +77,214 @@ export class TurboDocxError extends Error {
     // Maintains proper stack trace for where error was thrown
     Error.captureStackTrace(this, this.constructor);
   }
+
+  /**
+   * Attach an actionable hint (and optionally a docs link) to the error.
+   * Fluent, so throw sites stay one expression:
+   * `throw new ValidationError('...').withHelp('did you forget senderEmail?')`
+   */
+  withHelp(help: string, docsUrl?: string): this {
+    this.help = help;
+    if (docsUrl) {
+      this.docsUrl = docsUrl;
+    }
+    return this;
+  }
+
+  /**
+   * True when this error carries the given code, either SDK-side on `code`
+   * or API-side on the structured error body. Accepts raw strings so codes
+   * newer than the enum can still be matched.
+   */
+  is(code: TurboDocxErrorCode | string): boolean {
+    return this.code === code || this.apiError?.code === code;
+  }
+
+  /** Attach the structured API error body. Fluent, like withHelp. */
+  withApiError(apiError: ApiErrorBody): this {
+    this.apiError = apiError;
+    return this;
+  }
+
+  /**
+   * Annotate an error with the operation it surfaced from, so logs identify
+   * the failing API call without needing a stack trace.
+   *
+   * TurboDocxErrors keep their subclass and properties and gain an operation
+   * prefix on the message; anything else is wrapped in a TurboDocxError with
+   * the original error preserved as its cause. The innermost operation wins,
+   * so re-annotating along the call chain is a no-op.
+   */
+  static inOperation(operation: string, error: unknown): TurboDocxError {
+    if (error instanceof TurboDocxError) {
+      if (!error.operation) {
+        error.operation = operation;
+        error.message = `${operation}: ${error.message}`;
+      }
+      return error;
+    }
+
+    const message = error instanceof Error ? error.message : String(error);
+    const wrapped = new TurboDocxError(`${operation}: ${message}`);
+    wrapped.operation = operation;
+    (wrapped as Error & { cause?: unknown }).cause = error;
+    return wrapped;
+  }
 }
 
 export class AuthenticationError extends TurboDocxError {
   constructor(message: string = 'Authentication failed') {
-    super(message, 401, 'AUTHENTICATION_ERROR');
+    super(message, 401, TurboDocxErrorCode.AuthenticationError);
     this.name = 'AuthenticationError';
   }
 }
 
 export class ValidationError extends TurboDocxError {
   constructor(message: string) {
-    super(message, 400, 'VALIDATION_ERROR');
+    super(message, 400, TurboDocxErrorCode.ValidationError);
     this.name = 'ValidationError';
   }
 }
 
 export class NotFoundError extends TurboDocxError {
   constructor(message: string = 'Resource not found') {
-    super(message, 404, 'NOT_FOUND');
+    super(message, 404, TurboDocxErrorCode.NotFound);
     this.name = 'NotFoundError';
   }
 }
 
+/**
+ * The document changed since the caller last read it (HTTP 409). Raised
+ * when an expectedVersion precondition fails — e.g. a racing automation
+ * tried to void a document that was just signed. Re-read the document and
+ * decide whether the mutation still makes sense.
+ */
+export class ConflictError extends TurboDocxError {
+  constructor(message: string = 'Resource version conflict') {
+    super(message, 409, TurboDocxErrorCode.Conflict);
+    this.name = 'ConflictError';
+  }
+}
+
+/**
+ * The document is in a state that cannot accept the requested operation —
+ * e.g. voiding a document that already completed. Raised by client-side
+ * pre-checks before any request is sent, so no HTTP status is attached;
+ * `currentStatus` names the state the check observed.
+ */
+export class InvalidStateError extends TurboDocxError {
+  /** Document status observed by the pre-check */
+  public readonly currentStatus?: string;
+
+  constructor(message: string, currentStatus?: string) {
+    super(message, undefined, TurboDocxErrorCode.InvalidState);
+    this.name = 'InvalidStateError';
+    this.currentStatus = currentStatus;
+  }
+}
+
 export class RateLimitError extends TurboDocxError {
   constructor(message: string = 'Rate limit exceeded') {
-    super(message, 429, 'RATE_LIMIT_EXCEEDED');
+    super(message, 429, TurboDocxErrorCode.RateLimitExceeded);
     this.name = 'RateLimitError';
   }
 }
 
-export class NetworkError extends TurboDocxError {
+export class IntegrityError extends TurboDocxError {
   constructor(message: string) {
-    super(message, undefined, 'NETWORK_ERROR');
+    super(message, undefined, TurboDocxErrorCode.IntegrityError);
+    this.name = 'IntegrityError';
+  }
+}
+
+export class NetworkError extends TurboDocxError {
+  constructor(message: string, code: string = TurboDocxErrorCode.NetworkError) {
+    super(message, undefined, code);
     this.name = 'NetworkError';
   }
 }
+
+/**
+ * The org's remaining monthly quota cannot cover the planned work. Carries
+ * the credit numbers so batch drivers can report how far over they are.
+ */
+export class QuotaExceededError extends TurboDocxError {
+  /** Credits the planned work would consume */
+  public readonly estimatedCredits?: number;
+  /** Credits left on the org's plan */
+  public readonly creditsRemaining?: number;
+
+  constructor(message: string, estimatedCredits?: number, creditsRemaining?: number) {
+    super(message, undefined, TurboDocxErrorCode.QuotaExceeded);
+    this.name = 'QuotaExceededError';
+    this.estimatedCredits = estimatedCredits;
+    this.creditsRemaining = creditsRemaining;
+  }
+}
+
+/**
+ * The planned work fits, but would drop remaining quota below the caller's
+ * low-water mark. Batch drivers catch this to pause or degrade rather than
+ * burning the quota and failing the tail of the batch.
+ */
+export class QuotaLowError extends TurboDocxError {
+  /** Credits the planned work would consume */
+  public readonly estimatedCredits?: number;
+  /** Credits left on the org's plan */
+  public readonly creditsRemaining?: number;
+
+  constructor(message: string, estimatedCredits?: number, creditsRemaining?: number) {
+    super(message, undefined, TurboDocxErrorCode.QuotaLow);
+    this.name = 'QuotaLowError';
+    this.estimatedCredits = estimatedCredits;
+    this.creditsRemaining = creditsRemaining;
+  }
+}
+
+/**
+ * The client-side circuit breaker is open: recent requests failed past the
+ * configured threshold, so the client fails fast instead of sending more
+ * traffic to a degraded API. Retry after the open duration elapses.
+ */
+export class CircuitOpenError extends TurboDocxError {
+  constructor(message: string = 'Circuit breaker is open') {
+    super(message, undefined, TurboDocxErrorCode.CircuitOpen);
+    this.name = 'CircuitOpenError';
+  }
+}
+
+/**
+ * The client has been shut down: shutdown() was called, so new requests are
+ * rejected while in-flight ones drain. Seen by code that keeps issuing calls
+ * after SIGTERM handling has begun.
+ */
+export class ClientClosedError extends TurboDocxError {
+  constructor(message: string = 'Client is shut down and no longer accepts requests') {
+    super(message, undefined, TurboDocxErrorCode.ClientClosed);
+    this.name = 'ClientClosedError';
+  }
+}
+
+/**
+ * A request exceeded the configured timeoutMs. Subclass of NetworkError so
+ * existing catch blocks keep working, with a distinct code for callers that
+ * treat timeouts differently from connection failures.
+ */
+export class TimeoutError extends NetworkError {
+  constructor(message: string = 'Request timed out') {
+    super(message, TurboDocxErrorCode.Timeout);
+    this.name = 'TimeoutError';
+  }
+}
+
+/**
+ * The caller aborted the request via the per-call AbortSignal — e.g. a user
+ * cancelled the operation in the UI while an upload was in flight. Subclass
+ * of NetworkError like TimeoutError, with a distinct code; never retried.
+ */
+export class CancelledError extends NetworkError {
+  constructor(message: string = 'Request was cancelled') {
+    super(message, TurboDocxErrorCode.Cancelled);
+    this.name = 'CancelledError';
+  }
+}
@@ -0,0 +1,364 @@
+/**
+ * Field validation helpers for TurboSign
+ */
+
+import { CoordinateSystem, CoordinateUnit, CustomFieldDefinition, CustomFieldValues, DocumentMode, Field, Recipient, SignatureFieldType } from '../types/sign';
+import { ValidationError } from './errors';
+
+/** Problem classes reported by checkFieldCoverage */
+export type FieldCoverageIssueType =
+  | 'missingSignatureField'
+  | 'recipientWithoutFields'
+  | 'unknownRecipientEmail';
+
+export interface FieldCoverageIssue {
+  /** Problem class, stable for programmatic handling */
+  type: FieldCoverageIssueType;
+  /** Recipient email the issue relates to */
+  recipientEmail: string;
+  /** Human-readable description, suitable for display in a UI */
+  message: string;
+}
+
+export interface FieldCoverageReport {
+  /** True when no issues were found */
+  ok: boolean;
+  /** One entry per problem found */
+  issues: FieldCoverageIssue[];
+}
+
+/**
+ * Validate an IANA time zone name before uploading anything.
+ *
+ * Auto-filled Date fields render in the configured time zone; a typo'd zone
+ * would otherwise surface server-side after the document is created. Checked
+ * against the runtime's own tz database via Intl.
+ *
+ * @param timeZone - IANA zone name, e.g. 'America/New_York'
+ * @param context - Where the zone came from, for the error message
+ * @throws ValidationError when the zone is unknown
+ */
+export function validateTimeZone(timeZone: string, context: string = 'timeZone'): void {
+  try {
+    new Intl.DateTimeFormat('en-US', { timeZone });
+  } catch {
+    throw new ValidationError(
+      `Invalid ${context} '${timeZone}': not a known IANA time zone. Use a zone name like 'America/New_York'.`
+    );
+  }
+}
+
+/**
+ * Validate custom document property values against the org's schema.
+ *
+ * Rejects unknown keys, missing required properties, type mismatches, and
+ * enum values outside the allowed list, so a typo'd property fails before
+ * the document is uploaded rather than server-side.
+ *
+ * @param values - Property values about to be sent
+ * @param definitions - Schema fetched via TurboSign.getCustomFieldDefinitions
+ * @throws ValidationError on the first mismatch found
+ */
+export function validateCustomFieldValues(
+  values: CustomFieldValues,
+  definitions: CustomFieldDefinition[]
+): void {
+  const byKey = new Map(definitions.map((d) => [d.key, d]));
+
+  for (const [key, value] of Object.entries(values)) {
+    const definition = byKey.get(key);
+    if (!definition) {
+      const known = definitions.map((d) => d.key).join(', ') || '(none defined)';
+      throw new ValidationError(`Unknown custom field '${key}'. Defined keys: ${known}.`);
+    }
+
+    switch (definition.type) {
+      case 'string':
+        if (typeof value !== 'string') {
+          throw new ValidationError(`Custom field '${key}' must be a string.`);
+        }
+        break;
+      case 'number':
+        if (typeof value !== 'number') {
+          throw new ValidationError(`Custom field '${key}' must be a number.`);
+        }
+        break;
+      case 'boolean':
+        if (typeof value !== 'boolean') {
+          throw new ValidationError(`Custom field '${key}' must be a boolean.`);
+        }
+        break;
+      case 'date':
+        if (typeof value !== 'string' || Number.isNaN(Date.parse(value))) {
+          throw new ValidationError(`Custom field '${key}' must be an ISO 8601 date string.`);
+        }
+        break;
+      case 'enum':
+        if (!definition.allowedValues?.includes(String(value))) {
+          throw new ValidationError(
+            `Custom field '${key}' must be one of: ${definition.allowedValues?.join(', ') ?? '(no allowed values defined)'}.`
+          );
+        }
+        break;
+    }
+  }
+
+  for (const definition of definitions) {
+    if (definition.required && !(definition.key in values)) {
+      throw new ValidationError(`Custom field '${definition.key}' is required.`);
+    }
+  }
+}
+
+/**
+ * Check that recipients and fields cover each other before sending.
+ *
+ * Reports recipients without a signature field (not required for form-mode
+ * documents), recipients referenced by no
+ * field at all, and fields that reference an email not in the recipient
+ * list. Returns a structured report rather than throwing, so the result can
+ * be surfaced in a UI.
+ *
+ * @param request - The recipients and fields about to be sent
+ *
+ * @example
+ * ```typescript
+ * const report = checkFieldCoverage({ recipients, fields });
+ * if (!report.ok) {
+ *   for (const issue of report.issues) console.warn(issue.message);
+ * }
+ * ```
+ */
+export function checkFieldCoverage(request: {
+  recipients: Recipient[];
+  fields: Field[];
+  /** Completion mode — form-mode documents don't need signature fields */
+  mode?: DocumentMode;
+}): FieldCoverageReport {
+  const issues: FieldCoverageIssue[] = [];
+  const recipientEmails = new Set(request.recipients.map((r) => r.email));
+
+  for (const recipient of request.recipients) {
+    const recipientFields = request.fields.filter(
+      (f) => f.recipientEmail === recipient.email
+    );
+
+    if (recipientFields.length === 0) {
+      issues.push({
+        type: 'recipientWithoutFields',
+        recipientEmail: recipient.email,
+        message: `Recipient ${recipient.email} is not referenced by any field.`,
+      });
+    } else if (request.mode !== 'form' && !recipientFields.some((f) => f.type === 'signature')) {
+      issues.push({
+        type: 'missingSignatureField',
+        recipientEmail: recipient.email,
+        message: `Recipient ${recipient.email} has no signature field.`,
+      });
+    }
+  }
+
+  const reportedUnknown = new Set<string>();
+  for (const field of request.fields) {
+    if (!recipientEmails.has(field.recipientEmail) && !reportedUnknown.has(field.recipientEmail)) {
+      reportedUnknown.add(field.recipientEmail);
+      issues.push({
+        type: 'unknownRecipientEmail',
+        recipientEmail: field.recipientEmail,
+        message: `Field references ${field.recipientEmail}, which is not in the recipient list.`,
+      });
+    }
+  }
+
+  return { ok: issues.length === 0, issues };
+}
+
+export interface FieldSize {
+  width: number;
+  height: number;
+}
+
+/** Unit used for field sizes. Pixels assume 96 DPI, points are 1/72 inch. */
+export type FieldSizeUnit = 'pixels' | 'points';
+
+/** Conversion factor from 96 DPI pixels to PDF points (72 DPI) */
+const POINTS_PER_PIXEL = 72 / 96;
+
+/** Default field sizes in pixels, keyed by field type */
+const DEFAULT_FIELD_SIZES: Record<SignatureFieldType, FieldSize> = {
+  signature: { width: 200, height: 50 },
+  initial: { width: 80, height: 40 },
+  date: { width: 100, height: 30 },
+  text: { width: 150, height: 30 },
+  full_name: { width: 180, height: 30 },
+  title: { width: 150, height: 30 },
+  company: { width: 180, height: 30 },
+  first_name: { width: 120, height: 30 },
+  last_name: { width: 120, height: 30 },
+  email: { width: 200, height: 30 },
+  checkbox: { width: 20, height: 20 },
+};
+
+/** Approximate width of one character in pixels at the default font size */
+const CHAR_WIDTH_PX = 8;
+
+/** Field types whose width scales with the expected content length */
+const TEXT_LIKE_TYPES: SignatureFieldType[] = [
+  'text', 'full_name', 'title', 'company', 'first_name', 'last_name', 'email',
+];
+
+/**
+ * Compute a sensible field size for a field type, optionally scaled to the
+ * expected content.
+ *
+ * Replaces the hardcoded width/height numbers seen in every example:
+ * signatures default to 200x50, dates to 100x30, and text-like fields are
+ * scaled to the character count of the content hint.
+ *
+ * @param type - Field type to size
+ * @param contentHint - Expected content (text-like fields are widened to fit it)
+ * @param unit - Unit for the returned size: 'pixels' (default, 96 DPI) or 'points' (72 DPI)
+ * @returns Width and height in the requested unit
+ *
+ * @example
+ * ```typescript
+ * const size = autoFieldSize('full_name', 'Alexandra Montgomery-Smith');
+ * const field = { type: 'full_name', page: 1, x: 100, y: 500, ...size, recipientEmail: 'a@example.com' };
+ * ```
+ */
+export function autoFieldSize(
+  type: SignatureFieldType,
+  contentHint?: string,
+  unit: FieldSizeUnit = 'pixels'
+): FieldSize {
+  const base = DEFAULT_FIELD_SIZES[type];
+  let size: FieldSize = { ...base };
+
+  if (contentHint && TEXT_LIKE_TYPES.includes(type)) {
+    // Widen to fit the expected content, never narrower than the default
+    size.width = Math.max(base.width, Math.ceil(contentHint.length * CHAR_WIDTH_PX));
+  }
+
+  if (unit === 'points') {
+    size = {
+      width: Math.round(size.width * POINTS_PER_PIXEL),
+      height: Math.round(size.height * POINTS_PER_PIXEL),
+    };
+  }
+
+  return size;
+}
+
+/** Pixels (96 DPI) per unit, keyed by coordinate unit */
+const PIXELS_PER_UNIT: Record<CoordinateUnit, number> = {
+  pixels: 1,
+  points: 96 / 72,
+  inches: 96,
+  millimeters: 96 / 25.4,
+};
+
+/**
+ * Convert a length from the given unit to pixels (96 DPI).
+ */
+export function toPixels(value: number, unit: CoordinateUnit): number {
+  return value * PIXELS_PER_UNIT[unit];
+}
+
+/**
+ * Convert field coordinates and dimensions from the given unit to the
+ * pixels the API expects. Fields without coordinates (anchor-based) are
+ * returned unchanged; 'pixels' is a no-op.
+ *
+ * @param fields - Fields as supplied by the caller
+ * @param unit - Unit the caller used (default 'pixels')
+ */
+export function convertFieldUnits(fields: Field[], unit?: CoordinateUnit): Field[] {
+  if (!unit || unit === 'pixels') {
+    return fields;
+  }
+
+  return fields.map((field) => {
+    const converted = { ...field };
+    if (field.x !== undefined) converted.x = toPixels(field.x, unit);
+    if (field.y !== undefined) converted.y = toPixels(field.y, unit);
+    if (field.width !== undefined) converted.width = toPixels(field.width, unit);
+    if (field.height !== undefined) converted.height = toPixels(field.height, unit);
+    return converted;
+  });
+}
+
+/**
+ * Flip a field's y coordinate between top-left and bottom-left origins.
+ *
+ * The TurboSign API measures y from the top-left of the page, while
+ * PDF-native tooling measures from the bottom-left — mixing the two lands
+ * fields upside-down. The conversion is symmetric, so the same helper maps
+ * in either direction.
+ *
+ * @param field - Coordinate-based field to convert
+ * @param pageHeight - Page height in the same unit as the field coordinates
+ * @returns A copy of the field with the y coordinate flipped
+ */
+export function flipFieldY(field: Field, pageHeight: number): Field {
+  if (field.y === undefined || field.height === undefined) {
+    return { ...field };
+  }
+  return { ...field, y: pageHeight - field.y - field.height };
+}
+
+/**
+ * Convert fields to the top-left origin the API expects.
+ * Fields already in 'topLeft' (or anchor-based fields without coordinates)
+ * are returned unchanged.
+ *
+ * @param fields - Fields as supplied by the caller
+ * @param coordinateSystem - Origin the caller used (default 'topLeft')
+ * @param pageHeight - Page height, required when converting from 'bottomLeft'
+ * @throws ValidationError if coordinateSystem is 'bottomLeft' and pageHeight is missing
+ */
+export function normalizeCoordinates(
+  fields: Field[],
+  coordinateSystem?: CoordinateSystem,
+  pageHeight?: number
+): Field[] {
+  if (!coordinateSystem || coordinateSystem === 'topLeft') {
+    return fields;
+  }
+
+  if (pageHeight === undefined) {
+    throw new ValidationError(
+      "pageHeight is required when coordinateSystem is 'bottomLeft' so y coordinates can be converted to the top-left origin."
+    );
+  }
+
+  return fields.map((field) => flipFieldY(field, pageHeight));
+}
+
+/**
+ * Validate tab order across fields.
+ * Each recipient's fields must not reuse the same tabIndex, otherwise the
+ * signer would be walked through fields in an undefined order.
+ *
+ * @param fields - Fields to validate
+ * @throws ValidationError if two fields for the same recipient share a tabIndex
+ */
+export function validateTabOrder(fields: Field[]): void {
+  const seen = new Map<string, Set<number>>();
+
+  for (const field of fields) {
+    if (field.tabIndex === undefined) continue;
+
+    let indexes = seen.get(field.recipientEmail);
+    if (!indexes) {
+      indexes = new Set<number>();
+      seen.set(field.recipientEmail, indexes);
+    }
+
+    if (indexes.has(field.tabIndex)) {
+      throw new ValidationError(
+        `Duplicate tabIndex ${field.tabIndex} for recipient ${field.recipientEmail}. Each field for a recipient must have a unique tabIndex.`
+      );
+    }
+    indexes.add(field.tabIndex);
+  }
+}
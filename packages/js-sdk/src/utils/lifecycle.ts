@@ -0,0 +1,90 @@
+/**
+ * Lifecycle state machines for documents and deliverables
+ *
+ * The allowed status transitions in one place, with canTransition/isTerminal
+ * helpers, so validation pre-checks and the watch helpers share a single
+ * rule set instead of encoding transition rules in scattered if-statements.
+ *
+ * Statuses this SDK does not know about (added by a newer API) are treated
+ * permissively: they are never terminal and may transition anywhere — the
+ * server stays the authority for states the client cannot reason about.
+ */
+
+/** Known signature document statuses */
+export type DocumentLifecycleStatus =
+  | 'draft'
+  | 'sent'
+  | 'under_review'
+  | 'completed'
+  | 'voided'
+  | 'declined'
+  | 'expired';
+
+/** Known deliverable generation statuses */
+export type DeliverableLifecycleStatus =
+  | 'generating'
+  | 'ready'
+  | 'archived'
+  | 'failed'
+  | 'cancelled';
+
+/**
+ * Allowed signature document transitions. A status mapping to an empty
+ * list is terminal: the document can never change state again.
+ */
+export const DOCUMENT_TRANSITIONS: Readonly<Record<DocumentLifecycleStatus, readonly DocumentLifecycleStatus[]>> = {
+  draft: ['sent', 'under_review', 'voided'],
+  sent: ['under_review', 'completed', 'voided', 'declined', 'expired'],
+  under_review: ['completed', 'voided', 'declined', 'expired'],
+  completed: [],
+  voided: [],
+  declined: [],
+  expired: [],
+};
+
+/** Allowed deliverable transitions, same shape as DOCUMENT_TRANSITIONS */
+export const DELIVERABLE_TRANSITIONS: Readonly<Record<DeliverableLifecycleStatus, readonly DeliverableLifecycleStatus[]>> = {
+  generating: ['ready', 'failed', 'cancelled'],
+  ready: ['archived'],
+  archived: [],
+  failed: [],
+  cancelled: [],
+};
+
+function canTransition(
+  transitions: Readonly<Record<string, readonly string[]>>,
+  from: string,
+  to: string
+): boolean {
+  const allowed = transitions[from] as readonly string[] | undefined;
+  // Unknown current status — let the server decide
+  if (!allowed) {
+    return true;
+  }
+  return allowed.includes(to);
+}
+
+function isTerminal(transitions: Readonly<Record<string, readonly string[]>>, status: string): boolean {
+  const allowed = transitions[status] as readonly string[] | undefined;
+  return allowed !== undefined && allowed.length === 0;
+}
+
+/** True when a signature document may move from one status to another */
+export function canDocumentTransition(from: string, to: string): boolean {
+  return canTransition(DOCUMENT_TRANSITIONS, from, to);
+}
+
+/** True when a deliverable may move from one status to another */
+export function canDeliverableTransition(from: string, to: string): boolean {
+  return canTransition(DELIVERABLE_TRANSITIONS, from, to);
+}
+
+/** True when a signature document status can never change again */
+export function isTerminalDocumentStatus(status: string): boolean {
+  return isTerminal(DOCUMENT_TRANSITIONS, status);
+}
+
+/** True when a deliverable status can never change again */
+export function isTerminalDeliverableStatus(status: string): boolean {
+  return isTerminal(DELIVERABLE_TRANSITIONS, status);
+}
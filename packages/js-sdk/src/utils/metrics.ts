@@ -0,0 +1,57 @@
+/**
+ * Metrics sink interface for SLO instrumentation
+ *
+ * The sink receives one record per request attempt, pre-aggregated into
+ * the dimensions dashboards need: a low-cardinality endpoint label (IDs
+ * replaced with ':id') and the status class. Wire it to whatever metrics
+ * library the service uses — counters keyed by endpoint + statusClass,
+ * latency histograms fed from durationMs — without monkey-patching the
+ * SDK. Sink exceptions are swallowed.
+ */
+
+/** Coarse outcome bucket for counters ('error' means no response at all) */
+export type StatusClass = '1xx' | '2xx' | '3xx' | '4xx' | '5xx' | 'error';
+
+/** One request attempt, as reported to a MetricsSink */
+export interface RequestMetric {
+  /** HTTP method */
+  method: string;
+  /** Request path relative to the base URL, as sent */
+  path: string;
+  /**
+   * Path with ID segments replaced by ':id' — the label to aggregate on,
+   * so per-document paths don't explode metric cardinality
+   */
+  endpoint: string;
+  /** HTTP status, or undefined when the attempt never produced a response */
+  status?: number;
+  /** Status bucket for counters */
+  statusClass: StatusClass;
+  /** Wall-clock time of the attempt in milliseconds */
+  durationMs: number;
+}
+
+/** Receives per-request metrics. Registered via HttpClientConfig.metricsSink. */
+export interface MetricsSink {
+  recordRequest(metric: RequestMetric): void;
+}
+
+const UUID_SEGMENT = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i;
+const ID_SEGMENT = /^(?=.*\d)[A-Za-z0-9_-]{6,}$/;
+
+/** Replace ID-shaped path segments with ':id' for a bounded label set */
+export function normalizeEndpoint(path: string): string {
+  const [pathname] = path.split('?');
+  return pathname
+    .split('/')
+    .map((segment) => (UUID_SEGMENT.test(segment) || ID_SEGMENT.test(segment) ? ':id' : segment))
+    .join('/');
+}
+
+/** Bucket an HTTP status into its class; no status means the request failed outright */
+export function toStatusClass(status?: number): StatusClass {
+  if (status === undefined) {
+    return 'error';
+  }
+  return `${Math.floor(status / 100)}xx` as StatusClass;
+}
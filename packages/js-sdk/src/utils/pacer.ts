@@ -0,0 +1,94 @@
+/**
+ * Pacer — token bucket with jitter for spreading bursts of API calls
+ *
+ * A fixed pacingMs delay spreads rows evenly but cannot absorb small
+ * bursts, and identical delays across processes synchronize into waves
+ * that trip anti-abuse controls. The pacer allows `burst` operations
+ * immediately, refills capacity at `ratePerSecond`, and adds random
+ * jitter to every wait — so a weekly reminder job can spread thousands
+ * of resends over an hour instead of firing them as one burst.
+ *
+ * Used by sendBulkFromCsv when a pacer is passed in place of pacingMs,
+ * and available directly for caller-driven resend loops.
+ */
+
+import { ValidationError } from './errors';
+
+/** Options for Pacer */
+export interface PacerOptions {
+  /** Sustained rate, in operations per second */
+  ratePerSecond: number;
+  /** Operations allowed immediately before pacing kicks in (default 1) */
+  burst?: number;
+  /** Random 0..jitterMs added to every wait so parallel loops desynchronize (default 0) */
+  jitterMs?: number;
+}
+
+export class Pacer {
+  private readonly ratePerSecond: number;
+  private readonly burst: number;
+  private readonly jitterMs: number;
+  private tokens: number;
+  private lastRefillAt: number;
+
+  constructor(options: PacerOptions) {
+    if (
+      typeof options.ratePerSecond !== 'number' ||
+      !Number.isFinite(options.ratePerSecond) ||
+      options.ratePerSecond <= 0
+    ) {
+      throw new ValidationError(
+        `ratePerSecond must be a finite number > 0, got ${JSON.stringify(options.ratePerSecond)}.`
+      );
+    }
+    if (options.burst !== undefined && (!Number.isInteger(options.burst) || options.burst < 1)) {
+      throw new ValidationError(`burst must be an integer >= 1, got ${JSON.stringify(options.burst)}.`);
+    }
+    if (
+      options.jitterMs !== undefined &&
+      (typeof options.jitterMs !== 'number' || !Number.isFinite(options.jitterMs) || options.jitterMs < 0)
+    ) {
+      throw new ValidationError(`jitterMs must be a finite number >= 0, got ${JSON.stringify(options.jitterMs)}.`);
+    }
+    this.ratePerSecond = options.ratePerSecond;
+    this.burst = options.burst ?? 1;
+    this.jitterMs = options.jitterMs ?? 0;
+    this.tokens = this.burst;
+    this.lastRefillAt = Date.now();
+  }
+
+  /**
+   * Wait until the next operation may proceed, consuming one token
+   *
+   * Resolves immediately (plus jitter) while burst capacity remains,
+   * otherwise sleeps until the bucket has refilled enough for one token.
+   */
+  async acquire(): Promise<void> {
+    this.refill();
+    let waitMs = 0;
+    if (this.tokens < 1) {
+      waitMs = Math.ceil(((1 - this.tokens) / this.ratePerSecond) * 1000);
+    }
+    if (this.jitterMs > 0) {
+      waitMs += Math.random() * this.jitterMs;
+    }
+    if (waitMs > 0) {
+      await new Promise((resolve) => setTimeout(resolve, Math.round(waitMs)));
+      this.refill();
+    }
+    this.tokens -= 1;
+  }
+
+  /** Tokens currently available, for monitoring and tests */
+  available(): number {
+    this.refill();
+    return Math.max(0, this.tokens);
+  }
+
+  private refill(): void {
+    const now = Date.now();
+    const elapsedSeconds = (now - this.lastRefillAt) / 1000;
+    this.tokens = Math.min(this.burst, this.tokens + elapsedSeconds * this.ratePerSecond);
+    this.lastRefillAt = now;
+  }
+}
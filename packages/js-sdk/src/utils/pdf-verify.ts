@@ -0,0 +1,129 @@
+/**
+ * Offline tamper-evidence checks for signed PDFs
+ *
+ * Inspects the embedded digital signature structures of a downloaded PDF
+ * without any network call. The checks are structural (signature presence,
+ * ByteRange consistency, data appended after the signed range) — per the
+ * SDK's zero-dependency rule, cryptographic certificate chain validation is
+ * out of scope and must be done with a dedicated PDF signature tool.
+ */
+
+export interface PdfSignatureInfo {
+  /** Signature encoding, e.g. 'adbe.pkcs7.detached' or 'ETSI.CAdES.detached' */
+  subFilter?: string;
+  /** Signer name recorded in the signature dictionary */
+  signerName?: string;
+  /** Signing time recorded in the signature dictionary (PDF date string) */
+  signingTime?: string;
+  /** Whether the ByteRange is well-formed (starts at 0, offsets in bounds) */
+  byteRangeValid: boolean;
+  /** Whether the ByteRange covers the document up to the end of the file */
+  coversWholeDocument: boolean;
+}
+
+export interface PdfVerificationReport {
+  /** Whether the input is a PDF at all */
+  isPdf: boolean;
+  /** Number of digital signatures found */
+  signatureCount: number;
+  /** Per-signature details */
+  signatures: PdfSignatureInfo[];
+  /** Whether data was appended after the last signed byte range - a tamper indicator */
+  trailingDataAfterSignature: boolean;
+  /** True when at least one well-formed signature covers the whole document */
+  ok: boolean;
+  /** Human-readable explanations for any problems found */
+  warnings: string[];
+}
+
+/**
+ * Run offline tamper-evidence checks on a signed PDF.
+ *
+ * @param data - Raw PDF content
+ * @returns Structural verification report
+ *
+ * @example
+ * ```typescript
+ * const blob = await TurboSign.download(documentId);
+ * const report = verifySignedPdf(Buffer.from(await blob.arrayBuffer()));
+ * if (!report.ok) console.warn(report.warnings);
+ * ```
+ */
+export function verifySignedPdf(data: Buffer | ArrayBuffer | Uint8Array): PdfVerificationReport {
+  const buffer = Buffer.isBuffer(data) ? data : Buffer.from(data as ArrayBuffer);
+  // latin1 keeps one byte per character, so string offsets match byte offsets
+  const content = buffer.toString('latin1');
+
+  const report: PdfVerificationReport = {
+    isPdf: content.startsWith('%PDF'),
+    signatureCount: 0,
+    signatures: [],
+    trailingDataAfterSignature: false,
+    ok: false,
+    warnings: [],
+  };
+
+  if (!report.isPdf) {
+    report.warnings.push('Data is not a PDF (missing %PDF header).');
+    return report;
+  }
+
+  const byteRangePattern = /\/ByteRange\s*\[\s*(\d+)\s+(\d+)\s+(\d+)\s+(\d+)\s*\]/g;
+  let match: RegExpExecArray | null;
+
+  while ((match = byteRangePattern.exec(content)) !== null) {
+    const [start, firstLength, secondOffset, secondLength] = match
+      .slice(1, 5)
+      .map(Number);
+
+    // Look for signature dictionary metadata near the ByteRange entry
+    const context = content.slice(Math.max(0, match.index - 2000), match.index + 2000);
+    const subFilter = context.match(/\/SubFilter\s*\/([A-Za-z0-9.]+)/)?.[1];
+    const signerName = context.match(/\/Name\s*\(([^)]*)\)/)?.[1];
+    const signingTime = context.match(/\/M\s*\(D:([^)]*)\)/)?.[1];
+
+    const byteRangeValid =
+      start === 0 &&
+      firstLength > 0 &&
+      secondOffset > firstLength &&
+      secondOffset + secondLength <= buffer.length;
+    const coversWholeDocument =
+      byteRangeValid && secondOffset + secondLength === buffer.length;
+
+    if (!byteRangeValid) {
+      report.warnings.push(
+        `Signature ${report.signatureCount + 1} has a malformed ByteRange.`
+      );
+    }
+
+    report.signatures.push({
+      subFilter,
+      signerName,
+      signingTime,
+      byteRangeValid,
+      coversWholeDocument,
+    });
+    report.signatureCount++;
+  }
+
+  if (report.signatureCount === 0) {
+    report.warnings.push('No digital signature found in the PDF.');
+    return report;
+  }
+
+  // Incremental updates appended after the last signed range indicate the
+  // document was modified after signing
+  report.trailingDataAfterSignature = !report.signatures.some(
+    (s) => s.coversWholeDocument
+  );
+  if (report.trailingDataAfterSignature) {
+    report.warnings.push(
+      'Data was appended after the signed byte range - the document may have been modified after signing.'
+    );
+  }
+
+  report.ok =
+    report.signatures.every((s) => s.byteRangeValid) &&
+    !report.trailingDataAfterSignature;
+  return report;
+}
@@ -0,0 +1,88 @@
+/**
+ * Policy Normalization
+ *
+ * Watch cadence, retry counts, and bulk pacing often come from service
+ * config files rather than code, so a typo arrives here as an arbitrary
+ * JSON value. These helpers apply the documented defaults and validate
+ * shapes up front, turning a bad config into a clear ValidationError
+ * instead of odd runtime behavior (e.g. a NaN poll interval that spins
+ * the watch loop).
+ */
+
+import { ValidationError } from './errors';
+import type { BulkSendFromCsvOptions, WatchOptions } from '../types/sign';
+
+/** Requires a finite number of at least min, named for the error message */
+function requireNumber(value: unknown, name: string, min: number): number {
+  if (typeof value !== 'number' || !Number.isFinite(value) || value < min) {
+    throw new ValidationError(`${name} must be a finite number >= ${min}, got ${JSON.stringify(value)}.`);
+  }
+  return value;
+}
+
+/**
+ * Apply watch defaults and validate the polling cadence
+ *
+ * @param options - Watch options, possibly loaded from a config file
+ * @returns Options with every field populated
+ * @throws ValidationError for non-numeric or inconsistent intervals
+ */
+export function resolveWatchOptions(options: WatchOptions = {}): Required<WatchOptions> {
+  const pollIntervalMs = options.pollIntervalMs === undefined
+    ? 5000
+    : requireNumber(options.pollIntervalMs, 'pollIntervalMs', 1);
+  const maxPollIntervalMs = options.maxPollIntervalMs === undefined
+    ? Math.max(60000, pollIntervalMs)
+    : requireNumber(options.maxPollIntervalMs, 'maxPollIntervalMs', 1);
+  if (maxPollIntervalMs < pollIntervalMs) {
+    throw new ValidationError(
+      `maxPollIntervalMs (${maxPollIntervalMs}) must be >= pollIntervalMs (${pollIntervalMs}).`
+    );
+  }
+  return {
+    pollIntervalMs,
+    maxPollIntervalMs,
+    stopAtTerminal: options.stopAtTerminal ?? true,
+  };
+}
+
+/**
+ * Apply bulk-send defaults and validate the pacing
+ *
+ * @param options - Bulk send options, possibly loaded from a config file
+ * @returns Options with pacingMs populated
+ * @throws ValidationError for a missing templateId or invalid pacing
+ */
+export function resolveBulkSendOptions(
+  options: BulkSendFromCsvOptions
+): BulkSendFromCsvOptions & { pacingMs: number } {
+  if (!options.templateId) {
+    throw new ValidationError('templateId is required for bulk send.');
+  }
+  const pacingMs = options.pacingMs === undefined
+    ? 500
+    : requireNumber(options.pacingMs, 'pacingMs', 0);
+  return { ...options, pacingMs };
+}
+
+/**
+ * Validate the retry/timeout policy fields of a client config
+ *
+ * Called by the HttpClient constructor, so a config file with
+ * "maxAttempts": "3" (a string) fails at configure time rather than on
+ * the first retried request.
+ *
+ * @param config - The timeoutMs/maxAttempts fields of HttpClientConfig
+ * @throws ValidationError for non-numeric or out-of-range values
+ */
+export function validateRetryPolicy(config: { timeoutMs?: number; maxAttempts?: number }): void {
+  if (config.timeoutMs !== undefined) {
+    requireNumber(config.timeoutMs, 'timeoutMs', 1);
+  }
+  if (config.maxAttempts !== undefined) {
+    requireNumber(config.maxAttempts, 'maxAttempts', 1);
+    if (!Number.isInteger(config.maxAttempts)) {
+      throw new ValidationError(`maxAttempts must be an integer, got ${config.maxAttempts}.`);
+    }
+  }
+}
@@ -0,0 +1,73 @@
+/**
+ * Redaction helpers for debug logging
+ *
+ * Support tickets often need request/response dumps, but raw payloads leak
+ * API keys, recipient emails, file bytes, and signature values. These
+ * helpers produce a copy safe to paste into a ticket: secrets keep only
+ * their last characters, emails keep only their first, and byte payloads
+ * are replaced by their size.
+ */
+
+/** Header/body keys whose values are secrets and must never be printed */
+const SENSITIVE_KEYS = new Set([
+  'apikey',
+  'accesstoken',
+  'authorization',
+  'password',
+  'signature',
+  'signaturevalue',
+]);
+
+const EMAIL_PATTERN = /([A-Za-z0-9._%+-])[A-Za-z0-9._%+-]*@([A-Za-z0-9.-]+)/g;
+
+/** Mask email addresses in a string, keeping the first character and domain */
+export function redactEmails(text: string): string {
+  return text.replace(EMAIL_PATTERN, '$1***@$2');
+}
+
+/**
+ * Mask a secret, keeping the last four characters for correlation
+ * ('Bearer sk-live-…7f2a' → 'Bearer ****7f2a'). Short values are fully
+ * masked.
+ */
+export function maskSecret(value: unknown): string {
+  const text = String(value);
+  if (text.startsWith('Bearer ')) {
+    return `Bearer ${maskSecret(text.slice('Bearer '.length))}`;
+  }
+  return text.length > 8 ? `****${text.slice(-4)}` : '****';
+}
+
+/**
+ * Deep-copy a value with secrets masked, emails partially hidden, and byte
+ * payloads replaced by their size. Safe to JSON.stringify for logs.
+ */
+export function redactForLogging(value: unknown): unknown {
+  if (value === null || value === undefined) {
+    return value;
+  }
+  if (typeof Buffer !== 'undefined' && Buffer.isBuffer(value)) {
+    return `[${value.byteLength} bytes redacted]`;
+  }
+  if (value instanceof Uint8Array) {
+    return `[${value.byteLength} bytes redacted]`;
+  }
+  if (typeof value === 'string') {
+    return redactEmails(value);
+  }
+  if (Array.isArray(value)) {
+    return value.map(redactForLogging);
+  }
+  if (typeof value === 'object') {
+    const redacted: Record<string, unknown> = {};
+    for (const [key, entry] of Object.entries(value)) {
+      if (SENSITIVE_KEYS.has(key.toLowerCase().replace(/[^a-z]/g, ''))) {
+        redacted[key] = maskSecret(entry);
+      } else {
+        redacted[key] = redactForLogging(entry);
+      }
+    }
+    return redacted;
+  }
+  return value;
+}
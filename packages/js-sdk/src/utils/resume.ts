@@ -0,0 +1,46 @@
+/**
+ * Resume tokens for long-running list iteration
+ *
+ * A resume token is an opaque base64 string encoding the iterator's next
+ * offset. Iterators hand one to the onResumeToken callback after every
+ * yielded record, so a job killed mid-run can persist the last token and
+ * continue from the exact record it stopped at instead of restarting from
+ * page 1. Treat tokens as opaque — the encoding may change between SDK
+ * versions, but any SDK version can resume from the tokens it issued.
+ */
+
+import { ValidationError } from './errors';
+// Base64 goes through the runtime helpers: Buffer is not a global in
+// browsers or edge workers, and resume tokens must work everywhere
+import { fromBase64, toBase64 } from './runtime';
+
+interface ResumeState {
+  /** Offset of the next record to yield */
+  offset: number;
+}
+
+/** Encode an iterator position as an opaque resume token */
+export function encodeResumeToken(state: ResumeState): string {
+  return toBase64(JSON.stringify(state));
+}
+
+/**
+ * Decode a resume token back to an iterator position
+ *
+ * @throws ValidationError when the token is not one this SDK issued
+ */
+export function decodeResumeToken(token: string): ResumeState {
+  let state: unknown;
+  try {
+    state = JSON.parse(fromBase64(token));
+  } catch {
+    throw new ValidationError(`Invalid resume token: ${token}`);
+  }
+
+  const offset = (state as ResumeState)?.offset;
+  if (typeof offset !== 'number' || !Number.isInteger(offset) || offset < 0) {
+    throw new ValidationError(`Invalid resume token: ${token}`);
+  }
+
+  return { offset };
+}
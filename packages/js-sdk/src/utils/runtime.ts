@@ -0,0 +1,87 @@
+/**
+ * Runtime detection and Node built-in access
+ *
+ * The SDK runs in Node, browsers, and edge workers (Cloudflare Workers,
+ * Deno Deploy). Browser bundlers choke on top-level imports of Node
+ * built-ins, and workers have neither process nor fs. Node-only modules
+ * are therefore loaded lazily through these helpers and environment
+ * variables read through a guard, so the rest of the SDK stays
+ * runtime-agnostic. File-path inputs and disk downloads remain Node-only;
+ * other runtimes pass Buffers/Files or use in-memory downloads.
+ */
+
+import { ValidationError } from './errors';
+
+/** True when running under Node with fs/path available */
+export function isNode(): boolean {
+  return typeof process !== 'undefined' && !!process.versions?.node;
+}
+
+/** Read an environment variable, or undefined where no process exists */
+export function envVar(name: string): string | undefined {
+  return typeof process !== 'undefined' ? process.env?.[name] : undefined;
+}
+
+/** Load Node's fs, with a pointed error in browsers and workers */
+export function requireFs(): typeof import('fs') {
+  if (!isNode()) {
+    throw new ValidationError(
+      'File paths and disk downloads are only supported in Node. In browsers and workers, pass a Buffer, File, or fileLink instead.'
+    );
+  }
+  return require('fs');
+}
+
+/** Load Node's path, with a pointed error in browsers and workers */
+export function requirePath(): typeof import('path') {
+  if (!isNode()) {
+    throw new ValidationError(
+      'File paths are only supported in Node. In browsers and workers, pass a Buffer, File, or fileLink instead.'
+    );
+  }
+  return require('path');
+}
+
+/** Load Node's crypto (for hashing), with a pointed error elsewhere */
+export function requireCrypto(): typeof import('crypto') {
+  if (!isNode()) {
+    throw new ValidationError('Checksum verification requires Node\'s crypto module.');
+  }
+  return require('crypto');
+}
+
+/** Generate a UUID via the Web Crypto global, present in Node 19+, browsers, and workers */
+export function randomUUID(): string {
+  if (typeof crypto !== 'undefined' && typeof crypto.randomUUID === 'function') {
+    return crypto.randomUUID();
+  }
+  return requireCrypto().randomUUID();
+}
+
+/** Base64-encode a UTF-8 string via Buffer in Node, btoa elsewhere */
+export function toBase64(text: string): string {
+  if (typeof Buffer !== 'undefined') {
+    return Buffer.from(text, 'utf8').toString('base64');
+  }
+  // btoa is byte-oriented: run the text through TextEncoder so multi-byte
+  // characters survive the round trip
+  const bytes = new TextEncoder().encode(text);
+  let binary = '';
+  for (const byte of bytes) {
+    binary += String.fromCharCode(byte);
+  }
+  return btoa(binary);
+}
+
+/** Decode a base64 string to UTF-8 via Buffer in Node, atob elsewhere */
+export function fromBase64(encoded: string): string {
+  if (typeof Buffer !== 'undefined') {
+    return Buffer.from(encoded, 'base64').toString('utf8');
+  }
+  const binary = atob(encoded);
+  const bytes = new Uint8Array(binary.length);
+  for (let i = 0; i < binary.length; i++) {
+    bytes[i] = binary.charCodeAt(i);
+  }
+  return new TextDecoder().decode(bytes);
+}
@@ -0,0 +1,66 @@
+/**
+ * Custom TLS trust for the HTTP client
+ *
+ * Deployments behind TLS-intercepting gateways re-sign traffic with an
+ * internal CA that Node does not trust by default. rootCertificates adds
+ * PEM roots to the trust store for SDK requests; insecureSkipTlsVerify
+ * disables verification entirely for development setups.
+ */
+
+import { ValidationError } from './errors';
+
+/** Client identity presented during the TLS handshake (mutual TLS) */
+export interface ClientCertificate {
+  /** PEM-encoded certificate (or chain) */
+  cert: string | Buffer;
+  /** PEM-encoded private key */
+  key: string | Buffer;
+  /** Passphrase for an encrypted private key */
+  passphrase?: string;
+}
+
+export interface TlsOptions {
+  /** Extra PEM-encoded root certificates to trust */
+  rootCertificates?: Array<string | Buffer>;
+  /** Disable certificate verification entirely — development only */
+  insecureSkipTlsVerify?: boolean;
+  /** Certificate and key to present for mutual TLS */
+  clientCertificate?: ClientCertificate;
+}
+
+/**
+ * Build a fetch dispatcher applying the given TLS trust settings
+ *
+ * Uses undici's Agent with custom connect options — like proxy support,
+ * this requires undici as an optional dependency since Node's bundled copy
+ * is not importable.
+ *
+ * @throws ValidationError when undici is missing
+ */
+export function createTlsDispatcher(options: TlsOptions): unknown {
+  let Agent: new (opts: Record<string, unknown>) => unknown;
+  try {
+    ({ Agent } = require('undici'));
+  } catch {
+    throw new ValidationError(
+      'Custom TLS settings require the optional undici package. Install it with: npm install undici'
+    );
+  }
+
+  const connect: Record<string, unknown> = {};
+  if (options.rootCertificates?.length) {
+    connect.ca = options.rootCertificates;
+  }
+  if (options.insecureSkipTlsVerify) {
+    connect.rejectUnauthorized = false;
+  }
+  if (options.clientCertificate) {
+    connect.cert = options.clientCertificate.cert;
+    connect.key = options.clientCertificate.key;
+    if (options.clientCertificate.passphrase !== undefined) {
+      connect.passphrase = options.clientCertificate.passphrase;
+    }
+  }
+
+  return new Agent({ connect });
+}
@@ -0,0 +1,36 @@
+/**
+ * Tracing interfaces for distributed trace integration
+ *
+ * The SDK has zero runtime dependencies, so instead of importing an
+ * OpenTelemetry API it accepts any tracer matching these structural
+ * interfaces — @opentelemetry/api's Tracer satisfies them as-is, so
+ * `trace.getTracer('turbodocx')` can be passed straight into
+ * HttpClientConfig.tracer. Every HTTP call becomes one span covering all
+ * retry attempts, with retries and errors recorded as span events.
+ */
+
+/** Attribute values a span accepts (the OpenTelemetry primitive set) */
+export type TraceAttributeValue = string | number | boolean;
+
+/**
+ * One span in a trace. Only setAttribute and end are required; the
+ * optional members are used when present, so minimal hand-rolled tracers
+ * work too.
+ */
+export interface TraceSpan {
+  /** Attach an attribute to the span */
+  setAttribute(key: string, value: TraceAttributeValue): unknown;
+  /** Record a point-in-time event (used for retries) */
+  addEvent?(name: string, attributes?: Record<string, TraceAttributeValue>): unknown;
+  /** Record a thrown error against the span */
+  recordException?(error: unknown): unknown;
+  /** Set the span outcome (code 2 is OpenTelemetry's ERROR) */
+  setStatus?(status: { code: number; message?: string }): unknown;
+  /** Finish the span */
+  end(): unknown;
+}
+
+/** Creates spans. Structurally compatible with @opentelemetry/api Tracer. */
+export interface Tracer {
+  startSpan(name: string, options?: { attributes?: Record<string, TraceAttributeValue> }): TraceSpan;
+}
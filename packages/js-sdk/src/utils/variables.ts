@@ -0,0 +1,540 @@
+/**
+ * Variable Builder Utilities
+ *
+ * Helpers for constructing DeliverableVariable objects safely, replacing
+ * the ad-hoc formatting snippets otherwise copy-pasted across services.
+ */
+
+import { DeliverableVariable, VariableMimeType, VariableStackEntry } from '../types/deliverable';
+import { ValidationError } from './errors';
+
+// ============================================
+// DATE VARIABLES
+// ============================================
+
+export type DateStyle = 'short' | 'medium' | 'long' | 'full';
+
+export interface DateVariableOptions {
+  /** Date style passed to Intl.DateTimeFormat (default 'medium') */
+  dateStyle?: DateStyle;
+  /** BCP 47 locale tag (default 'en-US') */
+  locale?: string;
+  /**
+   * Inject the raw ISO date (YYYY-MM-DD) instead of a localized string,
+   * leaving formatting to the server-side template engine
+   */
+  raw?: boolean;
+}
+
+/**
+ * Build a date variable with locale-aware formatting
+ *
+ * Formats via Intl.DateTimeFormat so output is correct for the target
+ * locale instead of hand-assembled date strings.
+ *
+ * @param placeholder - Template placeholder (e.g., "{SignedDate}")
+ * @param date - Date object or ISO 8601 date string
+ * @param options - Locale, date style, and raw ISO output
+ * @returns A text DeliverableVariable with the formatted date
+ * @throws {ValidationError} If the date or locale is invalid
+ *
+ * @example
+ * ```typescript
+ * dateVariable('{SignedDate}', new Date(), { locale: 'de-DE', dateStyle: 'long' });
+ * // { placeholder: '{SignedDate}', text: '31. August 2026', mimeType: 'text' }
+ * ```
+ */
+export function dateVariable(
+  placeholder: string,
+  date: Date | string,
+  options?: DateVariableOptions
+): DeliverableVariable {
+  const parsed = typeof date === 'string' ? new Date(date) : date;
+  if (isNaN(parsed.getTime())) {
+    throw new ValidationError(`Invalid date for ${placeholder}: ${date}`);
+  }
+
+  let text: string;
+  if (options?.raw) {
+    text = parsed.toISOString().slice(0, 10);
+  } else {
+    let formatter: Intl.DateTimeFormat;
+    try {
+      formatter = new Intl.DateTimeFormat(options?.locale ?? 'en-US', {
+        dateStyle: options?.dateStyle ?? 'medium',
+      });
+    } catch {
+      throw new ValidationError(`Invalid locale for ${placeholder}: ${options?.locale}`);
+    }
+    text = formatter.format(parsed);
+  }
+
+  return {
+    placeholder,
+    text,
+    mimeType: 'text',
+  };
+}
+
+// ============================================
+// FORMAT HINTS
+// ============================================
+
+/**
+ * A server-side formatting hint attached to a variable, so the template
+ * engine formats raw values and presentation logic stays out of business code
+ */
+export type FormatHint =
+  | `currency:${string}`
+  | `date:${string}`
+  | `number:${string}`
+  | 'percent';
+
+/**
+ * Builders for the engine's formatting hint syntax
+ *
+ * @example
+ * ```typescript
+ * formattedVariable('{Total}', 1249.5, FormatHints.currency('USD'));
+ * formattedVariable('{DueDate}', '2026-09-30', FormatHints.date('longDate'));
+ * ```
+ */
+export const FormatHints = {
+  /** Format a raw amount as currency (ISO 4217 code, e.g., "USD") */
+  currency(code: string): FormatHint {
+    if (!/^[A-Za-z]{3}$/.test(code)) {
+      throw new ValidationError(`Invalid ISO 4217 currency code: ${code}`);
+    }
+    return `currency:${code.toUpperCase()}`;
+  },
+
+  /** Format a raw ISO date using a named engine style (e.g., "longDate") */
+  date(style: string): FormatHint {
+    return `date:${style}`;
+  },
+
+  /** Format a raw number using an engine number pattern (e.g., "#,##0.00") */
+  number(pattern: string): FormatHint {
+    return `number:${pattern}`;
+  },
+
+  /** Format a raw ratio (0–1) as a percentage */
+  percent(): FormatHint {
+    return 'percent';
+  },
+};
+
+/**
+ * Build a text variable carrying a raw value plus a formatting hint
+ *
+ * The server-side engine applies the format, so callers pass unformatted
+ * values (numbers, ISO dates) instead of pre-rendered strings.
+ *
+ * @param placeholder - Template placeholder (e.g., "{Total}")
+ * @param value - Raw value; numbers are stringified as-is
+ * @param format - Formatting hint built via FormatHints
+ * @returns A text DeliverableVariable with the format hint attached
+ */
+export function formattedVariable(
+  placeholder: string,
+  value: string | number,
+  format: FormatHint
+): DeliverableVariable {
+  return {
+    placeholder,
+    text: String(value),
+    mimeType: 'text',
+    format,
+  };
+}
+
+// ============================================
+// LOOP VARIABLES
+// ============================================
+
+/** A single data row fed into a loop variable */
+export interface LoopRow {
+  [key: string]: unknown;
+}
+
+/** Per-row metadata mirroring the engine's `$index`, `$first`, `$last` */
+export interface LoopRowMeta {
+  /** Zero-based row index */
+  index: number;
+  /** True for the first row */
+  first: boolean;
+  /** True for the last row */
+  last: boolean;
+}
+
+export interface LoopVariableOptions {
+  /** mimeType of each rendered row (default 'text') */
+  mimeType?: VariableMimeType;
+  /**
+   * Row keys to total client-side. Each key adds an aggregate variable
+   * (e.g., key "amount" on "{LineItems}" adds "{LineItemsAmountSum}")
+   */
+  sum?: string[];
+}
+
+/**
+ * Build a loop variable from data rows, with client-side aggregates
+ *
+ * Each row is rendered into one variableStack entry. Inside the template the
+ * engine also exposes `$index`, `$first`, and `$last` per iteration. When
+ * `sum` keys are given, aggregate variables are computed client-side and
+ * returned alongside the loop: a row count ("{<Base>Count}") and one sum
+ * per key ("{<Base><Key>Sum}").
+ *
+ * @param placeholder - Loop placeholder (e.g., "{LineItems}")
+ * @param rows - Data rows to iterate
+ * @param render - Renders one row (with index/first/last metadata) to text
+ * @param options - Row mimeType and keys to sum
+ * @returns The loop variable followed by any aggregate variables
+ * @throws {ValidationError} If a summed key has a non-numeric value
+ *
+ * @example
+ * ```typescript
+ * const variables = loopVariable(
+ *   '{LineItems}',
+ *   [{ desc: 'Design', amount: 1200 }, { desc: 'Build', amount: 4800 }],
+ *   (row) => `${row.desc}: $${row.amount}`,
+ *   { sum: ['amount'] }
+ * );
+ * // [{LineItems} loop, {LineItemsCount} = "2", {LineItemsAmountSum} = "6000"]
+ * ```
+ */
+export function loopVariable(
+  placeholder: string,
+  rows: LoopRow[],
+  render: (row: LoopRow, meta: LoopRowMeta) => string,
+  options?: LoopVariableOptions
+): DeliverableVariable[] {
+  const mimeType = options?.mimeType ?? 'text';
+  const stack: VariableStackEntry[] = rows.map((row, index) => ({
+    text: render(row, { index, first: index === 0, last: index === rows.length - 1 }),
+    mimeType,
+  }));
+
+  const variables: DeliverableVariable[] = [
+    { placeholder, mimeType, variableStack: stack },
+  ];
+
+  const base = placeholder.replace(/[{}]/g, '');
+  variables.push({
+    placeholder: `{${base}Count}`,
+    text: String(rows.length),
+    mimeType: 'text',
+  });
+
+  for (const key of options?.sum ?? []) {
+    let total = 0;
+    for (const row of rows) {
+      const value = row[key];
+      if (typeof value !== 'number' || isNaN(value)) {
+        throw new ValidationError(`Cannot sum non-numeric value for key "${key}" in ${placeholder}`);
+      }
+      total += value;
+    }
+    const keyToken = key.charAt(0).toUpperCase() + key.slice(1);
+    variables.push({
+      placeholder: `{${base}${keyToken}Sum}`,
+      text: String(total),
+      mimeType: 'text',
+    });
+  }
+
+  return variables;
+}
+
+/**
+ * Group rows by a key for nested loops, preserving first-seen group order
+ *
+ * @param rows - Data rows to group
+ * @param key - Row key to group by
+ * @returns One entry per distinct key value, with that group's rows
+ *
+ * @example
+ * ```typescript
+ * loopGroupedBy(items, 'category');
+ * // [{ key: 'hardware', rows: [...] }, { key: 'services', rows: [...] }]
+ * ```
+ */
+export function loopGroupedBy(
+  rows: LoopRow[],
+  key: string
+): Array<{ key: string; rows: LoopRow[] }> {
+  const groups = new Map<string, LoopRow[]>();
+  for (const row of rows) {
+    const groupKey = String(row[key]);
+    const group = groups.get(groupKey);
+    if (group) {
+      group.push(row);
+    } else {
+      groups.set(groupKey, [row]);
+    }
+  }
+  return Array.from(groups, ([groupKey, groupRows]) => ({ key: groupKey, rows: groupRows }));
+}
+
+// ============================================
+// CONDITIONAL EXPRESSIONS
+// ============================================
+
+/**
+ * Typed builder for the engine's conditional expression syntax
+ *
+ * Expressions are assembled from comparisons and combinators instead of
+ * string concatenation, so field names and values are quoted and escaped
+ * consistently.
+ *
+ * @example
+ * ```typescript
+ * Expr.and(Expr.gt('total', 1000), Expr.eq('region', 'EU')).toString();
+ * // "total > 1000 && region == 'EU'"
+ * ```
+ */
+export class Expr {
+  private constructor(
+    private readonly expression: string,
+    /** True for and/or combinations, which need parens when nested */
+    private readonly compound: boolean = false
+  ) {}
+
+  /** field > value */
+  static gt(field: string, value: number): Expr {
+    return Expr.compare(field, '>', value);
+  }
+
+  /** field >= value */
+  static gte(field: string, value: number): Expr {
+    return Expr.compare(field, '>=', value);
+  }
+
+  /** field < value */
+  static lt(field: string, value: number): Expr {
+    return Expr.compare(field, '<', value);
+  }
+
+  /** field <= value */
+  static lte(field: string, value: number): Expr {
+    return Expr.compare(field, '<=', value);
+  }
+
+  /** field == value */
+  static eq(field: string, value: string | number | boolean): Expr {
+    return Expr.compare(field, '==', value);
+  }
+
+  /** field != value */
+  static ne(field: string, value: string | number | boolean): Expr {
+    return Expr.compare(field, '!=', value);
+  }
+
+  /** All expressions must hold */
+  static and(...exprs: Expr[]): Expr {
+    return Expr.combine('&&', exprs);
+  }
+
+  /** At least one expression must hold */
+  static or(...exprs: Expr[]): Expr {
+    return Expr.combine('||', exprs);
+  }
+
+  /** Negate an expression */
+  static not(expr: Expr): Expr {
+    return new Expr(`!(${expr.expression})`);
+  }
+
+  private static compare(field: string, op: string, value: string | number | boolean): Expr {
+    if (!/^[A-Za-z_][A-Za-z0-9_.]*$/.test(field)) {
+      throw new ValidationError(`Invalid expression field name: ${field}`);
+    }
+    const literal =
+      typeof value === 'string' ? `'${value.replace(/\\/g, '\\\\').replace(/'/g, "\\'")}'` : String(value);
+    return new Expr(`${field} ${op} ${literal}`);
+  }
+
+  private static combine(op: string, exprs: Expr[]): Expr {
+    if (exprs.length === 0) {
+      throw new ValidationError(`Expr.${op === '&&' ? 'and' : 'or'} requires at least one expression`);
+    }
+    if (exprs.length === 1) {
+      return exprs[0];
+    }
+    const terms = exprs.map((e) => (e.compound ? `(${e.expression})` : e.expression));
+    return new Expr(terms.join(` ${op} `), true);
+  }
+
+  /** The engine-syntax expression string */
+  toString(): string {
+    return this.expression;
+  }
+}
+
+/**
+ * Build a conditional variable from a typed expression
+ *
+ * The engine evaluates the expression against the other variable values and
+ * includes or hides the conditional block accordingly.
+ *
+ * @param placeholder - Conditional placeholder (e.g., "{ShowDiscount}")
+ * @param condition - Boolean value or an Expr built via the Expr combinators
+ * @returns A text DeliverableVariable carrying the condition
+ *
+ * @example
+ * ```typescript
+ * conditionalVariable('{ShowDiscount}', Expr.gt('total', 1000));
+ * ```
+ */
+export function conditionalVariable(placeholder: string, condition: boolean | Expr): DeliverableVariable {
+  return {
+    placeholder,
+    text: condition.toString(),
+    mimeType: 'text',
+  };
+}
+
+// ============================================
+// VARIABLE SETS
+// ============================================
+
+/**
+ * Composable collection of variables
+ *
+ * Lets common blocks (company header values, legal boilerplate) be defined
+ * once and composed into many generation requests. Later additions replace
+ * earlier ones with the same placeholder.
+ *
+ * @example
+ * ```typescript
+ * const companyHeader = VariableSet.from([
+ *   { placeholder: '{CompanyName}', text: 'TechCorp Inc.', mimeType: 'text' },
+ * ]);
+ *
+ * const variables = companyHeader
+ *   .merge(VariableSet.fromObject({ Total: 6000 }).prefix('order.'))
+ *   .toArray();
+ * ```
+ */
+export class VariableSet {
+  private readonly variables = new Map<string, DeliverableVariable>();
+
+  /** Create a set from existing variables */
+  static from(variables: DeliverableVariable[]): VariableSet {
+    return new VariableSet().add(...variables);
+  }
+
+  /** Create a set of text variables from a plain object, one per key */
+  static fromObject(values: Record<string, string | number | boolean>): VariableSet {
+    const set = new VariableSet();
+    for (const [key, value] of Object.entries(values)) {
+      set.add({ placeholder: `{${key}}`, text: String(value), mimeType: 'text' });
+    }
+    return set;
+  }
+
+  /** Add variables, replacing any existing ones with the same placeholder */
+  add(...variables: DeliverableVariable[]): this {
+    for (const variable of variables) {
+      this.variables.set(variable.placeholder, variable);
+    }
+    return this;
+  }
+
+  /** Merge another set into a new set; the other set wins on conflicts */
+  merge(other: VariableSet): VariableSet {
+    return VariableSet.from([...this.toArray(), ...other.toArray()]);
+  }
+
+  /**
+   * Return a new set with every placeholder token prefixed
+   * (e.g., prefix "customer." turns "{Name}" into "{customer.Name}")
+   */
+  prefix(prefix: string): VariableSet {
+    return VariableSet.from(
+      this.toArray().map((variable) => ({
+        ...variable,
+        placeholder: variable.placeholder.replace(/^\{/, `{${prefix}`),
+      }))
+    );
+  }
+
+  /** The composed variables, in insertion order */
+  toArray(): DeliverableVariable[] {
+    return Array.from(this.variables.values());
+  }
+}
+
+// ============================================
+// SUB-TEMPLATES
+// ============================================
+
+/**
+ * Build a variable whose content is another template, rendered server-side
+ *
+ * Enables modular documents (cover page + scoped SOW sections) assembled by
+ * the engine: the sub-template is generated with its own variables and the
+ * output is injected into the parent placeholder.
+ *
+ * @param placeholder - Parent placeholder to render the sub-template into
+ * @param templateId - Template ID to render
+ * @param variables - Variables for the sub-template's own placeholders
+ * @returns An html DeliverableVariable referencing the sub-template
+ *
+ * @example
+ * ```typescript
+ * subtemplateVariable('{SOWSection}', 'sow-template-id', [
+ *   { placeholder: '{Scope}', text: 'Phase 1 remediation', mimeType: 'text' },
+ * ]);
+ * ```
+ */
+export function subtemplateVariable(
+  placeholder: string,
+  templateId: string,
+  variables?: DeliverableVariable[]
+): DeliverableVariable {
+  return {
+    placeholder,
+    mimeType: 'html',
+    templateId,
+    subvariables: variables,
+  };
+}
+
+// ============================================
+// AUTO VARIABLES
+// ============================================
+
+/**
+ * Build a text variable from a bare token, normalizing the placeholder
+ *
+ * Accepts either "{CustomerName}" or "CustomerName" and always produces a
+ * braced placeholder, eliminating the recurring typo class where the token
+ * and the braces drift apart when written by hand.
+ *
+ * @param token - Placeholder token, with or without braces
+ * @param value - Value to inject
+ * @returns A text DeliverableVariable with a normalized placeholder
+ * @throws {ValidationError} If the token is empty or malformed
+ *
+ * @example
+ * ```typescript
+ * autoVariable('CustomerName', 'TechCorp Inc.');
+ * // { placeholder: '{CustomerName}', text: 'TechCorp Inc.', mimeType: 'text' }
+ * ```
+ */
+export function autoVariable(
+  token: string,
+  value: string | number | boolean
+): DeliverableVariable {
+  const bare = token.replace(/^\{/, '').replace(/\}$/, '');
+  if (!bare || /[{}\s]/.test(bare)) {
+    throw new ValidationError(`Invalid placeholder token: ${token}`);
+  }
+  return {
+    placeholder: `{${bare}}`,
+    text: String(value),
+    mimeType: 'text',
+  };
+}
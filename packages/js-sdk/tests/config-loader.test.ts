@@ -0,0 +1,94 @@
+/**
+ * Layered Config Loader Tests
+ *
+ * Tests for loadConfig precedence: config file < environment variables <
+ * programmatic overrides.
+ */
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { loadConfig } from '../src/utils/config';
+import { ValidationError } from '../src/utils/errors';
+
+describe('loadConfig', () => {
+  let configPath: string;
+
+  const writeConfig = (content: string): void => {
+    fs.writeFileSync(configPath, content);
+    process.env.TURBODOCX_CONFIG = configPath;
+  };
+
+  beforeEach(() => {
+    configPath = path.join(os.tmpdir(), `turbodocx-config-${process.pid}-${Date.now()}.json`);
+    delete process.env.TURBODOCX_CONFIG;
+    delete process.env.TURBODOCX_API_KEY;
+    delete process.env.TURBODOCX_ORG_ID;
+    delete process.env.TURBODOCX_SENDER_EMAIL;
+    delete process.env.TURBODOCX_SENDER_NAME;
+    delete process.env.TURBODOCX_BASE_URL;
+  });
+
+  afterEach(() => {
+    delete process.env.TURBODOCX_CONFIG;
+    if (fs.existsSync(configPath)) {
+      fs.unlinkSync(configPath);
+    }
+  });
+
+  it('should read settings from the config file', () => {
+    writeConfig(JSON.stringify({
+      apiKey: 'file-key',
+      orgId: 'file-org',
+      senderEmail: 'file@company.com',
+      timeoutMs: 20000,
+    }));
+
+    const config = loadConfig();
+
+    expect(config.apiKey).toBe('file-key');
+    expect(config.orgId).toBe('file-org');
+    expect(config.timeoutMs).toBe(20000);
+  });
+
+  it('should let environment variables override the file', () => {
+    writeConfig(JSON.stringify({ apiKey: 'file-key', orgId: 'file-org' }));
+    process.env.TURBODOCX_API_KEY = 'env-key';
+
+    const config = loadConfig();
+
+    expect(config.apiKey).toBe('env-key');
+    expect(config.orgId).toBe('file-org'); // untouched by env
+  });
+
+  it('should let programmatic overrides win over everything', () => {
+    writeConfig(JSON.stringify({ apiKey: 'file-key' }));
+    process.env.TURBODOCX_API_KEY = 'env-key';
+
+    const config = loadConfig({ apiKey: 'code-key' });
+
+    expect(config.apiKey).toBe('code-key');
+  });
+
+  it('should work without any config file', () => {
+    process.env.TURBODOCX_API_KEY = 'env-key';
+
+    const config = loadConfig({ senderEmail: 'code@company.com' });
+
+    expect(config.apiKey).toBe('env-key');
+    expect(config.senderEmail).toBe('code@company.com');
+  });
+
+  it('should fail when TURBODOCX_CONFIG points at a missing file', () => {
+    process.env.TURBODOCX_CONFIG = '/nonexistent/turbodocx.config.json';
+
+    expect(() => loadConfig()).toThrow(ValidationError);
+    expect(() => loadConfig()).toThrow('TURBODOCX_CONFIG');
+  });
+
+  it('should fail on malformed JSON', () => {
+    writeConfig('{ apiKey: unquoted }');
+
+    expect(() => loadConfig()).toThrow('not valid JSON');
+  });
+});
@@ -0,0 +1,55 @@
+/**
+ * CSV Parser Tests
+ *
+ * Tests for the zero-dependency CSV parsing behind sendBulkFromCsv.
+ */
+
+import { parseCsv, parseCsvRecords } from '../src/utils/csv';
+import { ValidationError } from '../src/utils/errors';
+
+describe('parseCsv', () => {
+  it('should split rows and fields', () => {
+    expect(parseCsv('a,b,c\nd,e,f')).toEqual([
+      ['a', 'b', 'c'],
+      ['d', 'e', 'f'],
+    ]);
+  });
+
+  it('should handle quoted fields with commas and newlines', () => {
+    expect(parseCsv('name,note\n"Doe, John","line one\nline two"')).toEqual([
+      ['name', 'note'],
+      ['Doe, John', 'line one\nline two'],
+    ]);
+  });
+
+  it('should unescape doubled quotes', () => {
+    expect(parseCsv('"say ""hi"""')).toEqual([['say "hi"']]);
+  });
+
+  it('should handle CRLF line endings and a trailing newline', () => {
+    expect(parseCsv('a,b\r\nc,d\r\n')).toEqual([
+      ['a', 'b'],
+      ['c', 'd'],
+    ]);
+  });
+
+  it('should reject unterminated quotes', () => {
+    expect(() => parseCsv('a,"unterminated')).toThrow(ValidationError);
+  });
+});
+
+describe('parseCsvRecords', () => {
+  it('should key rows by trimmed header names', () => {
+    const records = parseCsvRecords('name , email\nJohn Doe,john@example.com');
+    expect(records).toEqual([{ name: 'John Doe', email: 'john@example.com' }]);
+  });
+
+  it('should skip blank lines and pad short rows', () => {
+    const records = parseCsvRecords('name,email,team\n\nJohn Doe,john@example.com\n');
+    expect(records).toEqual([{ name: 'John Doe', email: 'john@example.com', team: '' }]);
+  });
+
+  it('should reject empty input', () => {
+    expect(() => parseCsvRecords('')).toThrow('CSV input is empty');
+  });
+});
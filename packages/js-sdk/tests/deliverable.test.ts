@@ -13,6 +13,7 @@
 
 import { Deliverable } from "../src/modules/deliverable";
 import { HttpClient } from "../src/http";
+import { ValidationError } from "../src/utils/errors";
 
 // Mock the HttpClient
 jest.mock("../src/http");
@@ -209,6 +210,185 @@ describe("Deliverable Module", () => {
 
       expect(result.results.deliverable.id).toBe("del-stack");
     });
+
+    it("should pass named section toggles through to the engine", async () => {
+      const mockResponse = {
+        results: {
+          deliverable: {
+            id: "del-sections",
+            name: "MSA - TechCorp",
+            description: "",
+            templateId: "tmpl-3",
+            createdBy: "user-1",
+            isActive: true,
+            createdOn: "2024-01-15T14:12:10.721Z",
+            updatedOn: "2024-01-15T14:12:10.721Z",
+          },
+        },
+      };
+
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue(mockResponse);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      await Deliverable.generateDeliverable({
+        name: "MSA - TechCorp",
+        templateId: "tmpl-3",
+        variables: [],
+        includeSections: ["warranty", "eu_annex"],
+      });
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        expect.objectContaining({ includeSections: ["warranty", "eu_annex"] })
+      );
+    });
+
+    it("should pass the queue priority through to the API", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        results: { deliverable: { id: "del-prio" } },
+      });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      await Deliverable.generateDeliverable({
+        name: "Quote - Interactive",
+        templateId: "tmpl-1",
+        variables: [],
+        priority: "high",
+      });
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        expect.objectContaining({ priority: "high" })
+      );
+    });
+
+    it("should surface generation diagnostics when requested", async () => {
+      const mockResponse = {
+        results: {
+          deliverable: {
+            id: "del-diag",
+            name: "Quarterly Report",
+            description: "",
+            templateId: "tmpl-4",
+            createdBy: "user-1",
+            isActive: true,
+            createdOn: "2024-01-15T14:12:10.721Z",
+            updatedOn: "2024-01-15T14:12:10.721Z",
+          },
+          diagnostics: {
+            substitutions: [{ placeholder: "{CompanyName}", count: 3 }],
+            unresolvedPlaceholders: ["{MissingValue}"],
+            warnings: ["Loop {LineItems} rendered 0 rows"],
+          },
+        },
+      };
+
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue(mockResponse);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.generateDeliverable({
+        name: "Quarterly Report",
+        templateId: "tmpl-4",
+        variables: [{ placeholder: "{CompanyName}", text: "TechCorp Inc.", mimeType: "text" }],
+        includeDiagnostics: true,
+      });
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        expect.objectContaining({ includeDiagnostics: true })
+      );
+      expect(result.results.diagnostics?.unresolvedPlaceholders).toEqual(["{MissingValue}"]);
+      expect(result.results.diagnostics?.warnings).toHaveLength(1);
+    });
+  });
+
+  describe("lock / unlock", () => {
+    it("should take and release the regeneration lock", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValueOnce({ deliverableId: "del-1", locked: true, lockedBy: "worker-a" })
+        .mockResolvedValueOnce({ deliverableId: "del-1", locked: false });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const locked = await Deliverable.lock("del-1");
+      const unlocked = await Deliverable.unlock("del-1");
+
+      expect(locked.locked).toBe(true);
+      expect(unlocked.locked).toBe(false);
+      expect(MockedHttpClient.prototype.post).toHaveBeenNthCalledWith(
+        1,
+        "/v1/deliverable/del-1/lock"
+      );
+      expect(MockedHttpClient.prototype.post).toHaveBeenNthCalledWith(
+        2,
+        "/v1/deliverable/del-1/unlock"
+      );
+    });
+
+    it("should surface a held lock as an error", async () => {
+      const lockError = {
+        statusCode: 400,
+        message: "Deliverable is locked by worker-b",
+        code: "VALIDATION_ERROR",
+      };
+      MockedHttpClient.prototype.post = jest.fn().mockRejectedValue(lockError);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      await expect(Deliverable.lock("del-1")).rejects.toEqual(lockError);
+    });
+  });
+
+  describe("cancelGeneration", () => {
+    it("should cancel a queued generation job", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        deliverableId: "del-1",
+        cancelled: true,
+        outcome: "cancelled",
+      });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.cancelGeneration("del-1");
+
+      expect(result.cancelled).toBe(true);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/v1/deliverable/del-1/cancel-generation"
+      );
+    });
+
+    it("should report when the job had already completed", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        deliverableId: "del-2",
+        cancelled: false,
+        outcome: "already_completed",
+        message: "Generation finished before the cancellation arrived",
+      });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.cancelGeneration("del-2");
+
+      expect(result.cancelled).toBe(false);
+      expect(result.outcome).toBe("already_completed");
+    });
+  });
+
+  describe("listSignaturesForDeliverable", () => {
+    it("should list TurboSign documents generated from a deliverable", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({
+        results: [
+          { documentId: "doc-1", name: "Contract - John Smith", status: "completed", createdOn: "2026-08-01T10:00:00Z" },
+          { documentId: "doc-2", name: "Contract - Jane Doe", status: "under_review" },
+        ],
+      });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const { results } = await Deliverable.listSignaturesForDeliverable("del-1");
+
+      expect(results).toHaveLength(2);
+      expect(results[0].documentId).toBe("doc-1");
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/v1/deliverable/del-1/signatures"
+      );
+    });
   });
 
   describe("getDeliverableDetails", () => {
@@ -319,6 +499,168 @@ describe("Deliverable Module", () => {
     });
   });
 
+  describe("createShareLink", () => {
+    it("should create a share link with expiry and permissions", async () => {
+      const mockResponse = {
+        url: "https://share.turbodocx.com/d/abc123",
+        deliverableId: "deliverable-uuid",
+        expiresOn: "2026-02-01T00:00:00.000Z",
+        allowDownload: true,
+        passwordProtected: false,
+      };
+
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.createShareLink("deliverable-uuid", {
+        expiresIn: 604800,
+        allowDownload: true,
+      });
+
+      expect(result.url).toBe("https://share.turbodocx.com/d/abc123");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/v1/deliverable/deliverable-uuid/share-link",
+        { expiresIn: 604800, allowDownload: true }
+      );
+    });
+  });
+
+  describe("listByTag", () => {
+    it("should filter the list by tag", async () => {
+      const mockResponse = {
+        results: [{ id: "del-q3", name: "Renewal - TechCorp" }],
+        totalRecords: 1,
+      };
+
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue(mockResponse);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.listByTag("renewal", { limit: 50 });
+
+      expect(result.totalRecords).toBe(1);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        { limit: 50, tag: "renewal" }
+      );
+    });
+  });
+
+  describe("findByExternalId", () => {
+    it("should return the matching deliverable", async () => {
+      const mockResponse = {
+        results: [{ id: "del-9182", name: "Order 9182", externalId: "order-9182" }],
+        totalRecords: 1,
+      };
+
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue(mockResponse);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const result = await Deliverable.findByExternalId("order-9182");
+
+      expect(result?.id).toBe("del-9182");
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        { externalId: "order-9182", limit: 1 }
+      );
+    });
+
+    it("should return null when nothing matches", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ results: [], totalRecords: 0 });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      expect(await Deliverable.findByExternalId("order-0000")).toBeNull();
+    });
+  });
+
+  describe("iterateDeliverables", () => {
+    it("should walk all pages and yield each record", async () => {
+      const makeRecord = (id: string) => ({ id, name: `Deliverable ${id}` });
+      const pageOne = {
+        results: [makeRecord("del-1"), makeRecord("del-2")],
+        totalRecords: 3,
+      };
+      const pageTwo = {
+        results: [makeRecord("del-3")],
+        totalRecords: 3,
+      };
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValueOnce(pageOne)
+        .mockResolvedValueOnce(pageTwo);
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const ids: string[] = [];
+      for await (const record of Deliverable.iterateDeliverables({ pageSize: 2 })) {
+        ids.push(record.id);
+      }
+
+      expect(ids).toEqual(["del-1", "del-2", "del-3"]);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledTimes(2);
+      expect(MockedHttpClient.prototype.get).toHaveBeenNthCalledWith(
+        1,
+        "/v1/deliverable",
+        { limit: 2, offset: 0 }
+      );
+      expect(MockedHttpClient.prototype.get).toHaveBeenNthCalledWith(
+        2,
+        "/v1/deliverable",
+        { limit: 2, offset: 2 }
+      );
+    });
+
+    it("should report a resume token after each record and resume from one", async () => {
+      const makeRecord = (id: string) => ({ id, name: `Deliverable ${id}` });
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValueOnce({
+        results: [makeRecord("del-1"), makeRecord("del-2")],
+        totalRecords: 2,
+      });
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const tokens: string[] = [];
+      for await (const record of Deliverable.iterateDeliverables({
+        pageSize: 10,
+        onResumeToken: (token) => tokens.push(token),
+      })) {
+        void record;
+      }
+
+      // One opaque token per yielded record
+      expect(tokens).toHaveLength(2);
+
+      // A fresh run resuming from the first token starts at the second record
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValueOnce({
+        results: [makeRecord("del-2")],
+        totalRecords: 2,
+      });
+      const resumedIds: string[] = [];
+      for await (const record of Deliverable.iterateDeliverables({
+        pageSize: 10,
+        resumeToken: tokens[0],
+      })) {
+        resumedIds.push(record.id);
+      }
+
+      expect(resumedIds).toEqual(["del-2"]);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/v1/deliverable",
+        { limit: 10, offset: 1 }
+      );
+    });
+
+    it("should reject a malformed resume token", async () => {
+      MockedHttpClient.prototype.get = jest.fn();
+      Deliverable.configure({ apiKey: "test-key", orgId: "org-1" });
+
+      const iterator = Deliverable.iterateDeliverables({ resumeToken: "not-a-token" });
+      await expect(iterator.next()).rejects.toThrow(ValidationError);
+    });
+  });
+
   describe("downloadSourceFile", () => {
     it("should download source file as ArrayBuffer", async () => {
       const mockArrayBuffer = new ArrayBuffer(1024);
@@ -330,7 +672,8 @@ describe("Deliverable Module", () => {
 
       expect(result).toBe(mockArrayBuffer);
       expect(MockedHttpClient.prototype.getRaw).toHaveBeenCalledWith(
-        "/v1/deliverable/file/del-1"
+        "/v1/deliverable/file/del-1",
+        undefined
       );
     });
   });
@@ -346,7 +689,8 @@ describe("Deliverable Module", () => {
 
       expect(result).toBe(mockArrayBuffer);
       expect(MockedHttpClient.prototype.getRaw).toHaveBeenCalledWith(
-        "/v1/deliverable/file/pdf/del-1"
+        "/v1/deliverable/file/pdf/del-1",
+        undefined
       );
     });
   });
@@ -0,0 +1,104 @@
+/**
+ * Error Operation Context Tests
+ *
+ * Tests for TurboDocxError.inOperation and the operation annotation the
+ * module clients apply to failures from HTTP calls.
+ */
+
+import { TurboSign } from "../src/modules/sign";
+import { HttpClient } from "../src/http";
+import { TurboDocxError, TurboDocxErrorCode, NotFoundError, ValidationError, TimeoutError } from "../src/utils/errors";
+
+// Mock the HttpClient
+jest.mock("../src/http");
+
+const MockedHttpClient = HttpClient as jest.MockedClass<typeof HttpClient>;
+
+describe("TurboDocxError.inOperation", () => {
+  it("should prefix the message and set operation on SDK errors", () => {
+    const error = new NotFoundError("Document not found");
+
+    const annotated = TurboDocxError.inOperation("TurboSign.getStatus", error);
+
+    expect(annotated).toBe(error); // same instance, subclass preserved
+    expect(annotated.operation).toBe("TurboSign.getStatus");
+    expect(annotated.message).toBe("TurboSign.getStatus: Document not found");
+    expect(annotated.statusCode).toBe(404);
+  });
+
+  it("should keep the innermost operation when annotated twice", () => {
+    const error = new NotFoundError("Document not found");
+
+    TurboDocxError.inOperation("TurboSign.getStatus", error);
+    const annotated = TurboDocxError.inOperation("TurboSign.getStatuses", error);
+
+    expect(annotated.operation).toBe("TurboSign.getStatus");
+    expect(annotated.message).toBe("TurboSign.getStatus: Document not found");
+  });
+
+  it("should wrap non-SDK errors preserving the source as cause", () => {
+    const source = new Error("missing field `status`");
+
+    const wrapped = TurboDocxError.inOperation("TurboSign.getAuditTrail", source);
+
+    expect(wrapped).toBeInstanceOf(TurboDocxError);
+    expect(wrapped.operation).toBe("TurboSign.getAuditTrail");
+    expect(wrapped.message).toBe("TurboSign.getAuditTrail: missing field `status`");
+    expect((wrapped as Error & { cause?: unknown }).cause).toBe(source);
+  });
+});
+
+describe("TurboDocxErrorCode", () => {
+  it("should be carried on SDK-side errors", () => {
+    expect(new ValidationError("bad request").code).toBe(TurboDocxErrorCode.ValidationError);
+    expect(new TimeoutError().code).toBe(TurboDocxErrorCode.Timeout);
+  });
+
+  it("should match SDK-side codes via is()", () => {
+    const error = new NotFoundError("Document not found");
+
+    expect(error.is(TurboDocxErrorCode.NotFound)).toBe(true);
+    expect(error.is(TurboDocxErrorCode.RateLimitExceeded)).toBe(false);
+  });
+
+  it("should match API-side codes from the structured error body", () => {
+    const error = new ValidationError("sender not verified").withApiError({
+      code: "SENDER_NOT_VERIFIED",
+      message: "sender not verified",
+    });
+
+    expect(error.is(TurboDocxErrorCode.SenderNotVerified)).toBe(true);
+    expect(error.is(TurboDocxErrorCode.ValidationError)).toBe(true);
+  });
+
+  it("should match raw strings for codes newer than the enum", () => {
+    const error = new ValidationError("odd failure").withApiError({ code: "BRAND_NEW_CODE" });
+
+    expect(error.is("BRAND_NEW_CODE")).toBe(true);
+  });
+});
+
+describe("operation context on module calls", () => {
+  beforeEach(() => {
+    jest.clearAllMocks();
+    (TurboSign as any).client = undefined;
+  });
+
+  it("should annotate errors with the failing operation", async () => {
+    MockedHttpClient.prototype.get = jest
+      .fn()
+      .mockRejectedValue(new NotFoundError("Document not found"));
+    TurboSign.configure({ apiKey: "test-key" });
+
+    let caught: TurboDocxError | undefined;
+    try {
+      await TurboSign.getAuditTrail("doc-404");
+    } catch (error) {
+      caught = error as TurboDocxError;
+    }
+
+    expect(caught).toBeInstanceOf(NotFoundError);
+    expect(caught?.operation).toBe("TurboSign.getAuditTrail");
+    expect(caught?.message).toContain("TurboSign.getAuditTrail");
+  });
+});
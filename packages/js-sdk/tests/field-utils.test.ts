@@ -0,0 +1,288 @@
+/**
+ * Field Utility Tests
+ *
+ * Tests for field validation and positioning helpers
+ */
+
+import {
+  validateTabOrder,
+  autoFieldSize,
+  flipFieldY,
+  normalizeCoordinates,
+  convertFieldUnits,
+  toPixels,
+  checkFieldCoverage,
+  validateCustomFieldValues,
+} from '../src/utils/fields';
+import { ValidationError } from '../src/utils/errors';
+import type { CustomFieldDefinition, Field } from '../src/types/sign';
+
+describe('Field Utilities', () => {
+  describe('validateTabOrder', () => {
+    const baseField: Field = {
+      type: 'signature',
+      page: 1,
+      x: 100,
+      y: 500,
+      width: 200,
+      height: 50,
+      recipientEmail: 'john@example.com',
+    };
+
+    it('should accept fields without tabIndex', () => {
+      expect(() => validateTabOrder([baseField, { ...baseField, y: 600 }])).not.toThrow();
+    });
+
+    it('should accept unique tabIndex values per recipient', () => {
+      const fields: Field[] = [
+        { ...baseField, tabIndex: 1 },
+        { ...baseField, type: 'date', tabIndex: 2 },
+      ];
+      expect(() => validateTabOrder(fields)).not.toThrow();
+    });
+
+    it('should allow the same tabIndex for different recipients', () => {
+      const fields: Field[] = [
+        { ...baseField, tabIndex: 1 },
+        { ...baseField, recipientEmail: 'jane@example.com', tabIndex: 1 },
+      ];
+      expect(() => validateTabOrder(fields)).not.toThrow();
+    });
+
+    it('should throw ValidationError on duplicate tabIndex for one recipient', () => {
+      const fields: Field[] = [
+        { ...baseField, tabIndex: 1 },
+        { ...baseField, type: 'date', tabIndex: 1 },
+      ];
+      expect(() => validateTabOrder(fields)).toThrow(ValidationError);
+      expect(() => validateTabOrder(fields)).toThrow(/Duplicate tabIndex 1.*john@example\.com/);
+    });
+  });
+
+  describe('autoFieldSize', () => {
+    it('should return the default signature size', () => {
+      expect(autoFieldSize('signature')).toEqual({ width: 200, height: 50 });
+    });
+
+    it('should return the default date size', () => {
+      expect(autoFieldSize('date')).toEqual({ width: 100, height: 30 });
+    });
+
+    it('should widen text-like fields to fit the content hint', () => {
+      const size = autoFieldSize('full_name', 'Alexandra Montgomery-Smith');
+      expect(size.width).toBeGreaterThan(180);
+      expect(size.height).toBe(30);
+    });
+
+    it('should never shrink below the default width', () => {
+      expect(autoFieldSize('full_name', 'Al')).toEqual({ width: 180, height: 30 });
+    });
+
+    it('should ignore content hints for signature fields', () => {
+      expect(autoFieldSize('signature', 'A very long content hint here')).toEqual({
+        width: 200,
+        height: 50,
+      });
+    });
+
+    it('should convert sizes to points when requested', () => {
+      expect(autoFieldSize('signature', undefined, 'points')).toEqual({ width: 150, height: 38 });
+    });
+  });
+
+  describe('coordinate conversion', () => {
+    const field: Field = {
+      type: 'signature',
+      page: 1,
+      x: 100,
+      y: 100,
+      width: 200,
+      height: 50,
+      recipientEmail: 'john@example.com',
+    };
+
+    it('should flip y relative to the page height', () => {
+      expect(flipFieldY(field, 792).y).toBe(792 - 100 - 50);
+    });
+
+    it('should be symmetric', () => {
+      expect(flipFieldY(flipFieldY(field, 792), 792).y).toBe(field.y);
+    });
+
+    it('should leave anchor-based fields without coordinates unchanged', () => {
+      const anchorField: Field = {
+        type: 'signature',
+        recipientEmail: 'john@example.com',
+        template: { anchor: '{Sig}' },
+      };
+      expect(flipFieldY(anchorField, 792)).toEqual(anchorField);
+    });
+
+    it('should not convert topLeft fields', () => {
+      expect(normalizeCoordinates([field], 'topLeft', 792)).toEqual([field]);
+      expect(normalizeCoordinates([field])).toEqual([field]);
+    });
+
+    it('should convert bottomLeft fields', () => {
+      const [converted] = normalizeCoordinates([field], 'bottomLeft', 792);
+      expect(converted.y).toBe(642);
+    });
+
+    it('should require pageHeight for bottomLeft conversion', () => {
+      expect(() => normalizeCoordinates([field], 'bottomLeft')).toThrow(ValidationError);
+    });
+  });
+
+  describe('unit conversion', () => {
+    const field: Field = {
+      type: 'signature',
+      page: 1,
+      x: 72,
+      y: 144,
+      width: 144,
+      height: 36,
+      recipientEmail: 'john@example.com',
+    };
+
+    it('should convert points to pixels', () => {
+      const [converted] = convertFieldUnits([field], 'points');
+      expect(converted).toMatchObject({ x: 96, y: 192, width: 192, height: 48 });
+    });
+
+    it('should convert inches to pixels', () => {
+      expect(toPixels(1, 'inches')).toBe(96);
+    });
+
+    it('should convert millimeters to pixels', () => {
+      expect(toPixels(25.4, 'millimeters')).toBeCloseTo(96);
+    });
+
+    it('should treat pixels as a no-op', () => {
+      expect(convertFieldUnits([field], 'pixels')).toEqual([field]);
+      expect(convertFieldUnits([field])).toEqual([field]);
+    });
+  });
+
+  describe('checkFieldCoverage', () => {
+    const john = { name: 'John Doe', email: 'john@example.com', signingOrder: 1 };
+    const jane = { name: 'Jane Smith', email: 'jane@example.com', signingOrder: 2 };
+    const signatureFor = (email: string): Field => ({
+      type: 'signature',
+      page: 1,
+      x: 100,
+      y: 500,
+      width: 200,
+      height: 50,
+      recipientEmail: email,
+    });
+
+    it('should pass when every recipient has a signature field', () => {
+      const report = checkFieldCoverage({
+        recipients: [john, jane],
+        fields: [signatureFor(john.email), signatureFor(jane.email)],
+      });
+      expect(report.ok).toBe(true);
+      expect(report.issues).toEqual([]);
+    });
+
+    it('should report recipients without any field', () => {
+      const report = checkFieldCoverage({
+        recipients: [john, jane],
+        fields: [signatureFor(john.email)],
+      });
+      expect(report.ok).toBe(false);
+      expect(report.issues).toEqual([
+        expect.objectContaining({
+          type: 'recipientWithoutFields',
+          recipientEmail: jane.email,
+        }),
+      ]);
+    });
+
+    it('should report recipients with fields but no signature field', () => {
+      const report = checkFieldCoverage({
+        recipients: [john],
+        fields: [{ ...signatureFor(john.email), type: 'date' }],
+      });
+      expect(report.issues[0].type).toBe('missingSignatureField');
+    });
+
+    it('should not require signature fields for form-mode documents', () => {
+      const report = checkFieldCoverage({
+        recipients: [john],
+        fields: [{ ...signatureFor(john.email), type: 'text' }],
+        mode: 'form',
+      });
+      expect(report.ok).toBe(true);
+    });
+
+    it('should report fields referencing unknown emails once per email', () => {
+      const report = checkFieldCoverage({
+        recipients: [john],
+        fields: [
+          signatureFor(john.email),
+          signatureFor('stranger@example.com'),
+          { ...signatureFor('stranger@example.com'), type: 'date' },
+        ],
+      });
+      const unknown = report.issues.filter((i) => i.type === 'unknownRecipientEmail');
+      expect(unknown).toHaveLength(1);
+      expect(unknown[0].recipientEmail).toBe('stranger@example.com');
+    });
+  });
+
+  describe('validateCustomFieldValues', () => {
+    const definitions: CustomFieldDefinition[] = [
+      { key: 'costCenter', type: 'string', required: true },
+      { key: 'headcount', type: 'number' },
+      { key: 'confidential', type: 'boolean' },
+      { key: 'effectiveDate', type: 'date' },
+      { key: 'contractType', type: 'enum', allowedValues: ['nda', 'msa', 'sow'] },
+    ];
+
+    it('should accept values matching the schema', () => {
+      expect(() =>
+        validateCustomFieldValues(
+          {
+            costCenter: 'CC-1042',
+            headcount: 12,
+            confidential: true,
+            effectiveDate: '2026-09-01',
+            contractType: 'msa',
+          },
+          definitions
+        )
+      ).not.toThrow();
+    });
+
+    it('should reject unknown keys listing the defined ones', () => {
+      expect(() => validateCustomFieldValues({ costCenter: 'CC-1', costCentre: 'CC-1' }, definitions)).toThrow(
+        "Unknown custom field 'costCentre'"
+      );
+    });
+
+    it('should reject type mismatches', () => {
+      expect(() => validateCustomFieldValues({ costCenter: 'CC-1', headcount: 'twelve' }, definitions)).toThrow(
+        "Custom field 'headcount' must be a number"
+      );
+    });
+
+    it('should reject unparseable date values', () => {
+      expect(() => validateCustomFieldValues({ costCenter: 'CC-1', effectiveDate: 'someday' }, definitions)).toThrow(
+        ValidationError
+      );
+    });
+
+    it('should reject enum values outside the allowed list', () => {
+      expect(() => validateCustomFieldValues({ costCenter: 'CC-1', contractType: 'lease' }, definitions)).toThrow(
+        "must be one of: nda, msa, sow"
+      );
+    });
+
+    it('should reject missing required values', () => {
+      expect(() => validateCustomFieldValues({ headcount: 3 }, definitions)).toThrow(
+        "Custom field 'costCenter' is required"
+      );
+    });
+  });
+});
@@ -0,0 +1,55 @@
+/**
+ * Fixture Tests
+ */
+
+import {
+  auditTrailResponseFixture,
+  createDeliverableResponseFixture,
+  createSignatureReviewLinkResponseFixture,
+  deliverableRecordFixture,
+  documentListItemFixture,
+  sendSignatureResponseFixture,
+} from '../src/testing';
+
+describe('response fixtures', () => {
+  it('should build a complete deliverable record', () => {
+    const record = deliverableRecordFixture();
+
+    expect(record.id).toBe('deliverable-fixture-id');
+    expect(record.isActive).toBe(true);
+  });
+
+  it('should apply overrides', () => {
+    const record = deliverableRecordFixture({ name: 'Custom Name', isActive: false });
+
+    expect(record.name).toBe('Custom Name');
+    expect(record.isActive).toBe(false);
+    expect(record.templateId).toBe('template-fixture-id');
+  });
+
+  it('should wrap the deliverable in a generation response', () => {
+    const response = createDeliverableResponseFixture({ id: 'del-42' });
+
+    expect(response.results.deliverable.id).toBe('del-42');
+  });
+
+  it('should build signature responses with recipients', () => {
+    expect(createSignatureReviewLinkResponseFixture().previewUrl).toContain('review');
+    expect(sendSignatureResponseFixture({ status: 'completed' }).status).toBe('completed');
+  });
+
+  it('should build an audit trail with a coherent event chain', () => {
+    const response = auditTrailResponseFixture();
+
+    expect(response.auditTrail.map((e) => e.actionType)).toEqual([
+      'document_prepared',
+      'email_sent',
+      'document_signed',
+    ]);
+    expect(response.auditTrail.every((e) => e.documentId === response.document.id)).toBe(true);
+  });
+
+  it('should build a document list item', () => {
+    expect(documentListItemFixture({ archived: true }).archived).toBe(true);
+  });
+});
@@ -0,0 +1,173 @@
+/**
+ * HTTP Client Cancellation and Deadline Tests
+ *
+ * Tests for the per-call signal and deadline options: caller aborts reject
+ * with CancelledError (never retried), and an absolute deadline bounds the
+ * whole call including retries.
+ */
+
+import { HttpClient } from '../src/http';
+import { CancelledError, NetworkError, TimeoutError } from '../src/utils/errors';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { success: true } }),
+};
+
+// fetch stub that hangs until its abort signal fires, then rejects the way
+// undici does when a request is aborted
+const hangingFetch = () =>
+  jest.fn((_url: string, init?: RequestInit) => {
+    return new Promise<Response>((_resolve, reject) => {
+      init?.signal?.addEventListener('abort', () => {
+        reject(new DOMException('This operation was aborted', 'AbortError'));
+      });
+    });
+  });
+
+const makeClient = (maxAttempts?: number) =>
+  new HttpClient({
+    apiKey: 'test-api-key',
+    orgId: 'test-org-id',
+    senderEmail: 'support@company.com',
+    maxAttempts,
+  });
+
+describe('HttpClient per-call signal', () => {
+  it('should reject with CancelledError when the signal is already aborted', async () => {
+    const mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+    const controller = new AbortController();
+    controller.abort();
+
+    await expect(
+      makeClient().get('/turbosign/documents', { signal: controller.signal })
+    ).rejects.toThrow(CancelledError);
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should abort an in-flight request when the signal fires', async () => {
+    global.fetch = hangingFetch() as unknown as typeof fetch;
+    const controller = new AbortController();
+    setTimeout(() => controller.abort(), 10);
+
+    await expect(
+      makeClient().get('/turbosign/documents', { signal: controller.signal })
+    ).rejects.toThrow(CancelledError);
+  });
+
+  it('should not retry a cancelled request', async () => {
+    const mockFetch = hangingFetch();
+    global.fetch = mockFetch as unknown as typeof fetch;
+    const controller = new AbortController();
+    setTimeout(() => controller.abort(), 10);
+
+    await expect(
+      makeClient(3).get('/turbosign/documents', { signal: controller.signal })
+    ).rejects.toThrow(CancelledError);
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+  });
+
+  it('should remain catchable as NetworkError for existing callers', async () => {
+    global.fetch = jest.fn() as unknown as typeof fetch;
+    const controller = new AbortController();
+    controller.abort();
+
+    const error = await makeClient()
+      .get('/turbosign/documents', { signal: controller.signal })
+      .catch((e) => e);
+
+    expect(error).toBeInstanceOf(NetworkError);
+    expect(error.code).toBe('CANCELLED');
+  });
+
+  it('should abort an in-flight raw download when the signal fires', async () => {
+    global.fetch = hangingFetch() as unknown as typeof fetch;
+    const controller = new AbortController();
+    setTimeout(() => controller.abort(), 10);
+
+    await expect(
+      makeClient().getRaw('/deliverable/doc-1/pdf', { signal: controller.signal })
+    ).rejects.toThrow(CancelledError);
+  });
+
+  it('should reject a streamed-to-disk download on a pre-aborted signal', async () => {
+    const mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+    const controller = new AbortController();
+    controller.abort();
+
+    await expect(
+      makeClient().getRawToFile('/deliverable/doc-1/pdf', '/tmp/doc.pdf', {
+        signal: controller.signal,
+      })
+    ).rejects.toThrow(CancelledError);
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should complete normally when the signal never fires', async () => {
+    global.fetch = jest.fn().mockResolvedValue(okResponse) as unknown as typeof fetch;
+    const controller = new AbortController();
+
+    const result = await makeClient().get('/turbosign/documents', {
+      signal: controller.signal,
+    });
+
+    expect(result).toEqual({ success: true });
+  });
+});
+
+describe('HttpClient per-call deadline', () => {
+  it('should fail immediately when the deadline has already passed', async () => {
+    const mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+
+    await expect(
+      makeClient().get('/turbosign/documents', { deadline: Date.now() - 1 })
+    ).rejects.toThrow('Deadline exceeded');
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should cap the attempt timeout to the time remaining', async () => {
+    global.fetch = hangingFetch() as unknown as typeof fetch;
+
+    await expect(
+      makeClient().get('/turbosign/documents', { deadline: Date.now() + 30 })
+    ).rejects.toThrow(TimeoutError);
+  });
+
+  it('should accept a Date deadline', async () => {
+    global.fetch = hangingFetch() as unknown as typeof fetch;
+
+    await expect(
+      makeClient().get('/turbosign/documents', { deadline: new Date(Date.now() + 30) })
+    ).rejects.toThrow(TimeoutError);
+  });
+
+  it('should stop retrying once the deadline passes', async () => {
+    const mockFetch = jest.fn().mockResolvedValue({
+      ok: false,
+      status: 503,
+      headers: { get: () => null },
+      json: async () => ({ message: 'service unavailable' }),
+    });
+    global.fetch = mockFetch as unknown as typeof fetch;
+
+    await expect(
+      makeClient(10).get('/turbosign/documents', { deadline: Date.now() + 50 })
+    ).rejects.toThrow(TimeoutError);
+    expect(mockFetch.mock.calls.length).toBeLessThan(10);
+  });
+
+  it('should complete normally within the deadline', async () => {
+    global.fetch = jest.fn().mockResolvedValue(okResponse) as unknown as typeof fetch;
+
+    const result = await makeClient().get('/turbosign/documents', {
+      deadline: Date.now() + 60_000,
+    });
+
+    expect(result).toEqual({ success: true });
+  });
+});
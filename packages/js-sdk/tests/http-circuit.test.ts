@@ -0,0 +1,129 @@
+/**
+ * HTTP Client Circuit Breaker Tests
+ *
+ * Tests for the opt-in circuitBreaker config option: consecutive transient
+ * failures open the circuit and subsequent requests fail fast with
+ * CircuitOpenError; after the open duration a probe can close it again.
+ */
+
+import { HttpClient } from '../src/http';
+import { CircuitOpenError, ValidationError } from '../src/utils/errors';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+const errorResponse = (status: number) => ({
+  ok: false,
+  status,
+  statusText: 'error',
+  headers: { get: () => 'application/json' },
+  json: async () => ({ message: `upstream ${status}` }),
+});
+
+const makeClient = (circuitBreaker: { failureThreshold?: number; openDurationMs?: number; halfOpenProbes?: number }) =>
+  new HttpClient({
+    apiKey: 'test-api-key',
+    orgId: 'test-org-id',
+    senderEmail: 'support@company.com',
+    circuitBreaker,
+  });
+
+describe('HttpClient circuitBreaker', () => {
+  let mockFetch: jest.Mock;
+
+  beforeEach(() => {
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  afterEach(() => {
+    jest.restoreAllMocks();
+  });
+
+  it('should open after the failure threshold and fail fast without fetching', async () => {
+    mockFetch.mockResolvedValue(errorResponse(503));
+    const client = makeClient({ failureThreshold: 2 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(CircuitOpenError);
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+  });
+
+  it('should count connection failures toward the threshold', async () => {
+    mockFetch.mockRejectedValue(new TypeError('fetch failed'));
+    const client = makeClient({ failureThreshold: 1 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('fetch failed');
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(CircuitOpenError);
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+  });
+
+  it('should not count deterministic errors toward the threshold', async () => {
+    mockFetch.mockResolvedValue(errorResponse(400));
+    const client = makeClient({ failureThreshold: 1 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(ValidationError);
+
+    // A 400 means the API is up; the circuit stays closed
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(ValidationError);
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+  });
+
+  it('should reset the failure count on success', async () => {
+    mockFetch
+      .mockResolvedValueOnce(errorResponse(503))
+      .mockResolvedValueOnce(okResponse)
+      .mockResolvedValueOnce(errorResponse(503));
+    const client = makeClient({ failureThreshold: 2 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+    await client.get('/turbosign/documents');
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+
+    // Only one consecutive failure, so the circuit is still closed
+    expect(mockFetch).toHaveBeenCalledTimes(3);
+  });
+
+  it('should close again after a successful half-open probe', async () => {
+    let now = 1_000_000;
+    jest.spyOn(Date, 'now').mockImplementation(() => now);
+
+    mockFetch.mockResolvedValueOnce(errorResponse(503)).mockResolvedValue(okResponse);
+    const client = makeClient({ failureThreshold: 1, openDurationMs: 5000 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(CircuitOpenError);
+
+    now += 5001;
+    const result = await client.get<{ ok: boolean }>('/turbosign/documents');
+    expect(result).toEqual({ ok: true });
+
+    // Closed again — subsequent requests flow normally
+    await client.get('/turbosign/documents');
+    expect(mockFetch).toHaveBeenCalledTimes(3);
+  });
+
+  it('should reopen when a half-open probe fails', async () => {
+    let now = 1_000_000;
+    jest.spyOn(Date, 'now').mockImplementation(() => now);
+
+    mockFetch.mockResolvedValue(errorResponse(503));
+    const client = makeClient({ failureThreshold: 1, openDurationMs: 5000 });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+
+    now += 5001;
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('upstream 503');
+
+    // Probe failed — back to open, without waiting out a new threshold
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(CircuitOpenError);
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+  });
+});
@@ -4,7 +4,7 @@
  * Tests for configuration validation including senderEmail/senderName requirements
  */
 
-import { HttpClient } from '../src/http';
+import { HttpClient, checkEnvConfig } from '../src/http';
 import { ValidationError, AuthenticationError } from '../src/utils/errors';
 
 describe('HttpClient Configuration', () => {
@@ -73,6 +73,147 @@ describe('HttpClient Configuration', () => {
     });
   });
 
+  describe('senderFallbackPolicy', () => {
+    it('should throw by default when senderEmail is missing', () => {
+      expect(() => {
+        new HttpClient({ apiKey: 'test-api-key', orgId: 'test-org-id' });
+      }).toThrow(ValidationError);
+    });
+
+    it('should warn and continue under the warn policy', () => {
+      const warnSpy = jest.spyOn(console, 'warn').mockImplementation();
+
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: 'test-org-id',
+          senderFallbackPolicy: 'warn',
+        });
+      }).not.toThrow();
+      expect(warnSpy).toHaveBeenCalledWith(expect.stringContaining('API Service User'));
+
+      warnSpy.mockRestore();
+    });
+
+    it('should continue silently under the allow policy', () => {
+      const warnSpy = jest.spyOn(console, 'warn').mockImplementation();
+
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: 'test-org-id',
+          senderFallbackPolicy: 'allow',
+        });
+      }).not.toThrow();
+      expect(warnSpy).not.toHaveBeenCalled();
+
+      warnSpy.mockRestore();
+    });
+
+    it('should not warn when senderEmail is configured', () => {
+      const warnSpy = jest.spyOn(console, 'warn').mockImplementation();
+
+      new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        senderFallbackPolicy: 'warn',
+      });
+      expect(warnSpy).not.toHaveBeenCalled();
+
+      warnSpy.mockRestore();
+    });
+  });
+
+  describe('eager config validation', () => {
+    it('should reject an empty apiKey', () => {
+      expect(() => {
+        new HttpClient({
+          apiKey: '',
+          orgId: 'test-org-id',
+          senderEmail: 'support@company.com',
+        });
+      }).toThrow(/apiKey must not be empty/);
+    });
+
+    it('should reject a whitespace-only apiKey from the environment', () => {
+      process.env.TURBODOCX_API_KEY = '   ';
+      expect(() => {
+        new HttpClient({
+          orgId: 'test-org-id',
+          senderEmail: 'support@company.com',
+        });
+      }).toThrow(ValidationError);
+    });
+
+    it('should reject a malformed baseUrl', () => {
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: 'test-org-id',
+          senderEmail: 'support@company.com',
+          baseUrl: 'api.turbodocx.com', // missing scheme
+        });
+      }).toThrow(/Invalid baseUrl/);
+    });
+
+    it('should reject a senderEmail without an @', () => {
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: 'test-org-id',
+          senderEmail: 'not-an-email',
+        });
+      }).toThrow(/Invalid senderEmail/);
+    });
+
+    it('should reject an empty orgId', () => {
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: '',
+          senderEmail: 'support@company.com',
+        });
+      }).toThrow(/orgId must not be empty/);
+    });
+
+    it('should accept a fully valid config', () => {
+      expect(() => {
+        new HttpClient({
+          apiKey: 'test-api-key',
+          orgId: 'test-org-id',
+          senderEmail: 'support@company.com',
+          baseUrl: 'https://staging.turbodocx.com',
+        });
+      }).not.toThrow();
+    });
+  });
+
+  describe('diagnostic help on configuration errors', () => {
+    it('should attach a hint and docs link when senderEmail is missing', () => {
+      let caught: ValidationError | undefined;
+      try {
+        new HttpClient({ apiKey: 'test-api-key', orgId: 'test-org-id' });
+      } catch (error) {
+        caught = error as ValidationError;
+      }
+
+      expect(caught?.help).toMatch(/did you forget senderEmail/i);
+      expect(caught?.docsUrl).toContain('docs.turbodocx.com');
+    });
+
+    it('should attach a hint when credentials are missing', () => {
+      let caught: AuthenticationError | undefined;
+      try {
+        new HttpClient({ orgId: 'test-org-id', senderEmail: 'support@company.com' });
+      } catch (error) {
+        caught = error as AuthenticationError;
+      }
+
+      expect(caught?.help).toContain('TURBODOCX_API_KEY');
+    });
+  });
+
   describe('senderName configuration', () => {
     it('should not throw error when senderName is not provided', () => {
       expect(() => {
@@ -185,6 +326,213 @@ describe('HttpClient Configuration', () => {
     });
   });
 
+  describe('user agent', () => {
+    const getHeaders = (client: HttpClient): Record<string, string> =>
+      (client as any).getHeaders();
+
+    it('should send the SDK user agent by default', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+      });
+      expect(getHeaders(client)['User-Agent']).toMatch(/^turbodocx-js-sdk\/\d+\.\d+\.\d+$/);
+    });
+
+    it('should append an application suffix', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        userAgentSuffix: 'my-service/1.2.0',
+      });
+      expect(getHeaders(client)['User-Agent']).toMatch(/^turbodocx-js-sdk\/\d+\.\d+\.\d+ my-service\/1\.2\.0$/);
+    });
+
+    it('should append appInfo as a product token', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        appInfo: { name: 'billing-exporter', version: '3.1.0' },
+      });
+      expect(getHeaders(client)['User-Agent']).toMatch(/^turbodocx-js-sdk\/\d+\.\d+\.\d+ billing-exporter\/3\.1\.0$/);
+    });
+
+    it('should allow appInfo without a version', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        appInfo: { name: 'billing-exporter' },
+      });
+      expect(getHeaders(client)['User-Agent']).toMatch(/ billing-exporter$/);
+    });
+
+    it('should omit the header when opted out', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        disableUserAgent: true,
+      });
+      expect(getHeaders(client)['User-Agent']).toBeUndefined();
+    });
+  });
+
+  describe('prebuilt headers', () => {
+    it('should assemble headers once and hand out fresh copies', () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+      });
+      const headers = (client as any).getHeaders();
+      // Copies, so one call's mutations can't leak into the next
+      expect((client as any).getHeaders()).not.toBe(headers);
+      expect((client as any).getHeaders()).toEqual(headers);
+      expect(headers['Authorization']).toBe('Bearer test-api-key');
+      expect(headers['x-rapiddocx-org-id']).toBe('test-org-id');
+      expect(headers['Content-Type']).toBe('application/json');
+    });
+
+    it('should still send Content-Type on JSON requests after a download', async () => {
+      const mockFetch = jest
+        .fn()
+        .mockResolvedValueOnce({
+          ok: true,
+          status: 200,
+          headers: new Headers(),
+          arrayBuffer: async () => new ArrayBuffer(4),
+        })
+        .mockResolvedValueOnce({
+          ok: true,
+          status: 200,
+          headers: { get: () => 'application/json' },
+          json: async () => ({ data: { ok: true } }),
+        });
+      global.fetch = mockFetch as unknown as typeof fetch;
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+      });
+
+      // Downloads strip Content-Type from their own headers; that must not
+      // bleed into later JSON requests
+      await client.getRaw('/turbosign/documents/doc-1/download');
+      await client.post('/turbosign/documents/doc-1/void', { reason: 'test' });
+
+      expect(mockFetch.mock.calls[0][1].headers['Content-Type']).toBeUndefined();
+      expect(mockFetch.mock.calls[1][1].headers['Content-Type']).toBe('application/json');
+    });
+  });
+
+  describe('compression', () => {
+    let mockFetch: jest.Mock;
+
+    beforeEach(() => {
+      mockFetch = jest.fn().mockResolvedValue({
+        ok: true,
+        status: 200,
+        headers: { get: () => 'application/json' },
+        json: async () => ({ data: { ok: true } }),
+      });
+      global.fetch = mockFetch as unknown as typeof fetch;
+    });
+
+    it('should negotiate gzip/brotli by default', async () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+      });
+
+      await client.get('/turbosign/documents');
+
+      expect(mockFetch.mock.calls[0][1].headers['Accept-Encoding']).toBe('gzip, br');
+    });
+
+    it('should request identity encoding when disabled', async () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        compression: false,
+      });
+
+      await client.get('/turbosign/documents');
+
+      expect(mockFetch.mock.calls[0][1].headers['Accept-Encoding']).toBe('identity');
+    });
+  });
+
+  describe('default headers', () => {
+    let mockFetch: jest.Mock;
+
+    beforeEach(() => {
+      mockFetch = jest.fn().mockResolvedValue({
+        ok: true,
+        status: 200,
+        headers: { get: () => 'application/json' },
+        json: async () => ({ data: { ok: true } }),
+      });
+      global.fetch = mockFetch as unknown as typeof fetch;
+    });
+
+    it('should send configured headers on every request', async () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        defaultHeaders: { 'X-Internal-Tenant': 'acme', 'X-Correlation-Id': 'req-42' },
+      });
+
+      await client.get('/turbosign/documents');
+
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['X-Internal-Tenant']).toBe('acme');
+      expect(headers['X-Correlation-Id']).toBe('req-42');
+    });
+
+    it('should not let default headers override auth, org, or content-type headers', async () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        defaultHeaders: {
+          Authorization: 'Bearer stolen',
+          'x-rapiddocx-org-id': 'other-org',
+          'Content-Type': 'text/plain',
+        },
+      });
+
+      await client.get('/turbosign/documents');
+
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['Authorization']).toBe('Bearer test-api-key');
+      expect(headers['x-rapiddocx-org-id']).toBe('test-org-id');
+      expect(headers['Content-Type']).toBe('application/json');
+    });
+
+    it('should drop reserved default headers regardless of casing', async () => {
+      const client = new HttpClient({
+        apiKey: 'test-api-key',
+        orgId: 'test-org-id',
+        senderEmail: 'support@company.com',
+        defaultHeaders: { 'content-type': 'text/plain' },
+      });
+
+      await client.get('/turbosign/documents');
+
+      // A lower-cased entry must not survive alongside the canonical one —
+      // fetch merges header names case-insensitively, so it would still win
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['content-type']).toBeUndefined();
+      expect(headers['Content-Type']).toBe('application/json');
+    });
+  });
+
   describe('full configuration', () => {
     it('should accept all configuration options', () => {
       const client = new HttpClient({
@@ -212,3 +560,72 @@ describe('HttpClient Configuration', () => {
     });
   });
 });
+
+describe('checkEnvConfig', () => {
+  beforeEach(() => {
+    delete process.env.TURBODOCX_API_KEY;
+    delete process.env.TURBODOCX_ORG_ID;
+    delete process.env.TURBODOCX_SENDER_EMAIL;
+    delete process.env.TURBODOCX_SENDER_NAME;
+    delete process.env.TURBODOCX_BASE_URL;
+  });
+
+  it('should report every missing variable with a clear detail', () => {
+    const report = checkEnvConfig();
+
+    expect(report.ok).toBe(false);
+    const variables = report.issues.map((issue) => issue.variable);
+    expect(variables).toContain('TURBODOCX_API_KEY');
+    expect(variables).toContain('TURBODOCX_ORG_ID');
+    expect(variables).toContain('TURBODOCX_SENDER_EMAIL');
+    expect(variables).toContain('TURBODOCX_SENDER_NAME');
+  });
+
+  it('should flag a malformed sender email', () => {
+    process.env.TURBODOCX_SENDER_EMAIL = 'not-an-email';
+
+    const report = checkEnvConfig();
+    const issue = report.issues.find((i) => i.variable === 'TURBODOCX_SENDER_EMAIL');
+
+    expect(issue?.problem).toBe('malformed');
+  });
+
+  it('should treat the missing sender name as optional', () => {
+    process.env.TURBODOCX_API_KEY = 'test-api-key';
+    process.env.TURBODOCX_ORG_ID = 'test-org-id';
+    process.env.TURBODOCX_SENDER_EMAIL = 'support@company.com';
+
+    const report = checkEnvConfig();
+
+    expect(report.ok).toBe(true);
+    expect(report.issues).toHaveLength(1);
+    expect(report.issues[0].variable).toBe('TURBODOCX_SENDER_NAME');
+    expect(report.issues[0].required).toBe(false);
+  });
+
+  it('should flag a malformed base URL as optional-but-broken', () => {
+    process.env.TURBODOCX_API_KEY = 'test-api-key';
+    process.env.TURBODOCX_ORG_ID = 'test-org-id';
+    process.env.TURBODOCX_SENDER_EMAIL = 'support@company.com';
+    process.env.TURBODOCX_SENDER_NAME = 'Support';
+    process.env.TURBODOCX_BASE_URL = 'api.turbodocx.com';
+
+    const report = checkEnvConfig();
+
+    expect(report.ok).toBe(true);
+    expect(report.issues[0].variable).toBe('TURBODOCX_BASE_URL');
+    expect(report.issues[0].problem).toBe('malformed');
+  });
+
+  it('should pass cleanly with a complete environment', () => {
+    process.env.TURBODOCX_API_KEY = 'test-api-key';
+    process.env.TURBODOCX_ORG_ID = 'test-org-id';
+    process.env.TURBODOCX_SENDER_EMAIL = 'support@company.com';
+    process.env.TURBODOCX_SENDER_NAME = 'Support';
+
+    const report = checkEnvConfig();
+
+    expect(report.ok).toBe(true);
+    expect(report.issues).toHaveLength(0);
+  });
+});
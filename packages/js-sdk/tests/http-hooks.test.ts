@@ -0,0 +1,98 @@
+/**
+ * HTTP Client Response Hook Tests
+ *
+ * Tests for the onResponse telemetry hook: fired per attempt, including
+ * failures and retries, and never allowed to break a request.
+ */
+
+import { HttpClient, ResponseEvent } from '../src/http';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+describe('HttpClient onResponse', () => {
+  let mockFetch: jest.Mock;
+  let events: ResponseEvent[];
+
+  const makeClient = (maxAttempts?: number) =>
+    new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      maxAttempts,
+      onResponse: (event) => events.push(event),
+    });
+
+  beforeEach(() => {
+    events = [];
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should report method, path, status, and duration on success', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient().get('/turbosign/documents');
+
+    expect(events).toHaveLength(1);
+    expect(events[0].method).toBe('GET');
+    expect(events[0].path).toBe('/turbosign/documents');
+    expect(events[0].status).toBe(200);
+    expect(events[0].durationMs).toBeGreaterThanOrEqual(0);
+    expect(events[0].error).toBeUndefined();
+  });
+
+  it('should report failures with the error and no status', async () => {
+    const failure = new TypeError('fetch failed');
+    mockFetch.mockRejectedValue(failure);
+
+    await expect(makeClient().get('/turbosign/documents')).rejects.toThrow();
+
+    expect(events).toHaveLength(1);
+    expect(events[0].status).toBeUndefined();
+    expect(events[0].error).toBe(failure);
+  });
+
+  it('should report every retried attempt', async () => {
+    mockFetch
+      .mockResolvedValueOnce({ ...okResponse, ok: false, status: 503, statusText: 'down', json: async () => ({}) })
+      .mockResolvedValueOnce(okResponse);
+
+    await makeClient(2).get('/turbosign/documents');
+
+    expect(events.map((e) => e.status)).toEqual([503, 200]);
+  });
+
+  it('should report error responses with their status', async () => {
+    mockFetch.mockResolvedValue({
+      ok: false,
+      status: 404,
+      statusText: 'Not Found',
+      headers: { get: () => 'application/json' },
+      json: async () => ({ message: 'Document not found' }),
+    });
+
+    await expect(makeClient().get('/turbosign/documents/doc-404')).rejects.toThrow();
+
+    expect(events).toHaveLength(1);
+    expect(events[0].status).toBe(404);
+  });
+
+  it('should swallow exceptions thrown by the hook', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      onResponse: () => {
+        throw new Error('telemetry backend down');
+      },
+    });
+
+    await expect(client.get('/turbosign/documents')).resolves.toEqual({ ok: true });
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * HTTP Client Idempotency-Key Tests
+ *
+ * POST requests carry an Idempotency-Key header so the backend can dedupe
+ * retried creates. Enabled automatically when retries are on (maxAttempts
+ * > 1), opt-in otherwise, and caller-supplied keys always win.
+ */
+
+import { HttpClient } from '../src/http';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+const errorResponse = (status: number) => ({
+  ok: false,
+  status,
+  statusText: 'error',
+  headers: { get: () => 'application/json' },
+  json: async () => ({ message: `upstream ${status}` }),
+});
+
+const makeClient = (config: { maxAttempts?: number; idempotencyKeys?: boolean } = {}) =>
+  new HttpClient({
+    apiKey: 'test-api-key',
+    orgId: 'test-org-id',
+    senderEmail: 'support@company.com',
+    ...config,
+  });
+
+const sentHeaders = (mockFetch: jest.Mock, call = 0): Record<string, string> =>
+  mockFetch.mock.calls[call][1].headers;
+
+describe('HttpClient Idempotency-Key', () => {
+  let mockFetch: jest.Mock;
+
+  beforeEach(() => {
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should attach a key to POST requests when retries are enabled', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ maxAttempts: 3 }).post('/turbosign/documents', {});
+
+    const key = sentHeaders(mockFetch)['Idempotency-Key'];
+    expect(key).toMatch(/^[0-9a-f-]{36}$/);
+  });
+
+  it('should reuse the same key across retry attempts', async () => {
+    mockFetch
+      .mockResolvedValueOnce(errorResponse(503))
+      .mockResolvedValueOnce(okResponse);
+
+    await makeClient({ maxAttempts: 2 }).post('/turbosign/documents', {});
+
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+    const first = sentHeaders(mockFetch, 0)['Idempotency-Key'];
+    const second = sentHeaders(mockFetch, 1)['Idempotency-Key'];
+    expect(first).toBeDefined();
+    expect(second).toBe(first);
+  });
+
+  it('should not attach a key to GET requests', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ maxAttempts: 3 }).get('/turbosign/documents');
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBeUndefined();
+  });
+
+  it('should not attach a key by default without retries', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient().post('/turbosign/documents', {});
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBeUndefined();
+  });
+
+  it('should attach a key when idempotencyKeys is enabled explicitly', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ idempotencyKeys: true }).post('/turbosign/documents', {});
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBeDefined();
+  });
+
+  it('should allow disabling keys even with retries enabled', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ maxAttempts: 3, idempotencyKeys: false }).post('/turbosign/documents', {});
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBeUndefined();
+  });
+
+  it('should keep a caller-supplied key', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ maxAttempts: 3 }).post('/turbosign/documents', {}, {
+      headers: { 'Idempotency-Key': 'caller-key-123' },
+    });
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBe('caller-key-123');
+  });
+
+  it('should attach a key to file uploads', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient({ maxAttempts: 2 }).uploadFile(
+      '/turbosign/single/prepare-for-signing',
+      Buffer.from('%PDF-1.4')
+    );
+
+    expect(sentHeaders(mockFetch)['Idempotency-Key']).toBeDefined();
+  });
+});
@@ -0,0 +1,57 @@
+/**
+ * Download Integrity Tests
+ *
+ * Tests for checksum validation of downloaded content
+ */
+
+import * as crypto from 'crypto';
+import { verifyDownloadIntegrity } from '../src/http';
+import { IntegrityError } from '../src/utils/errors';
+
+const body = Buffer.from('%PDF-1.7 test content');
+const data = body.buffer.slice(body.byteOffset, body.byteOffset + body.byteLength);
+const sha256 = crypto.createHash('sha256').update(body).digest('hex');
+const md5 = crypto.createHash('md5').update(body).digest('hex');
+const md5Base64 = crypto.createHash('md5').update(body).digest('base64');
+
+function responseWithHeaders(headers: Record<string, string>): Response {
+  return { headers: new Headers(headers) } as Response;
+}
+
+describe('verifyDownloadIntegrity', () => {
+  it('should return undefined when no hash header is present', () => {
+    expect(verifyDownloadIntegrity(data, responseWithHeaders({}))).toBeUndefined();
+  });
+
+  it('should validate against an x-checksum sha256 header', () => {
+    const response = responseWithHeaders({ 'x-checksum': `sha256:${sha256}` });
+    expect(verifyDownloadIntegrity(data, response)).toBe(sha256);
+  });
+
+  it('should validate against a bare hex x-checksum header', () => {
+    const response = responseWithHeaders({ 'x-checksum': sha256 });
+    expect(verifyDownloadIntegrity(data, response)).toBe(sha256);
+  });
+
+  it('should validate against a Content-MD5 header', () => {
+    const response = responseWithHeaders({ 'content-md5': md5Base64 });
+    expect(verifyDownloadIntegrity(data, response)).toBe(md5);
+  });
+
+  it('should ignore a Content-MD5 that is not a 16-byte hash', () => {
+    const response = responseWithHeaders({ 'content-md5': 'bm90LWEtaGFzaA==' });
+    expect(verifyDownloadIntegrity(data, response)).toBeUndefined();
+  });
+
+  it('should never verify against ETags, which are often not content hashes', () => {
+    // A 32-hex ETag that is NOT the body's MD5 must not fail the download
+    const response = responseWithHeaders({ etag: `"${'0'.repeat(32)}"` });
+    expect(verifyDownloadIntegrity(data, response)).toBeUndefined();
+  });
+
+  it('should throw IntegrityError on checksum mismatch', () => {
+    const response = responseWithHeaders({ 'x-checksum': `sha256:${'0'.repeat(64)}` });
+    expect(() => verifyDownloadIntegrity(data, response)).toThrow(IntegrityError);
+    expect(() => verifyDownloadIntegrity(data, response)).toThrow(/checksum validation/);
+  });
+});
@@ -0,0 +1,126 @@
+/**
+ * HTTP Client Metrics Tests
+ *
+ * Tests for the metricsSink integration: one record per request attempt
+ * with endpoint and status-class labels, plus the label helpers.
+ */
+
+import { HttpClient } from '../src/http';
+import { RequestMetric, normalizeEndpoint, toStatusClass } from '../src/utils/metrics';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+describe('normalizeEndpoint', () => {
+  it('should replace UUID segments with :id', () => {
+    expect(normalizeEndpoint('/turbosign/documents/123e4567-e89b-42d3-a456-426614174000/void')).toBe(
+      '/turbosign/documents/:id/void'
+    );
+  });
+
+  it('should replace other ID-shaped segments with :id', () => {
+    expect(normalizeEndpoint('/v1/deliverable/del-8842a1/share-link')).toBe(
+      '/v1/deliverable/:id/share-link'
+    );
+  });
+
+  it('should keep version and collection segments', () => {
+    expect(normalizeEndpoint('/v1/deliverable')).toBe('/v1/deliverable');
+    expect(normalizeEndpoint('/turbosign/verified-senders')).toBe('/turbosign/verified-senders');
+  });
+
+  it('should drop query strings', () => {
+    expect(normalizeEndpoint('/turbosign/documents?limit=10&offset=0')).toBe('/turbosign/documents');
+  });
+});
+
+describe('toStatusClass', () => {
+  it('should bucket statuses by hundreds', () => {
+    expect(toStatusClass(200)).toBe('2xx');
+    expect(toStatusClass(404)).toBe('4xx');
+    expect(toStatusClass(503)).toBe('5xx');
+  });
+
+  it('should report error when no response was produced', () => {
+    expect(toStatusClass(undefined)).toBe('error');
+  });
+});
+
+describe('HttpClient metricsSink', () => {
+  let mockFetch: jest.Mock;
+  let metrics: RequestMetric[];
+
+  const makeClient = (maxAttempts?: number) =>
+    new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      maxAttempts,
+      metricsSink: { recordRequest: (metric) => metrics.push(metric) },
+    });
+
+  beforeEach(() => {
+    metrics = [];
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should record endpoint, status class, and latency per request', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient().get('/turbosign/documents/doc-991234/status');
+
+    expect(metrics).toHaveLength(1);
+    expect(metrics[0]).toMatchObject({
+      method: 'GET',
+      endpoint: '/turbosign/documents/:id/status',
+      status: 200,
+      statusClass: '2xx',
+    });
+    expect(metrics[0].durationMs).toBeGreaterThanOrEqual(0);
+  });
+
+  it('should record each retry attempt separately', async () => {
+    mockFetch
+      .mockResolvedValueOnce({ ...okResponse, ok: false, status: 503 })
+      .mockResolvedValueOnce(okResponse);
+
+    await makeClient(2).get('/turbosign/documents');
+
+    expect(metrics).toHaveLength(2);
+    expect(metrics[0].statusClass).toBe('5xx');
+    expect(metrics[1].statusClass).toBe('2xx');
+  });
+
+  it('should record connection failures with the error class', async () => {
+    mockFetch.mockRejectedValue(new TypeError('fetch failed'));
+
+    await expect(makeClient().get('/turbosign/documents')).rejects.toThrow();
+
+    expect(metrics).toHaveLength(1);
+    expect(metrics[0].status).toBeUndefined();
+    expect(metrics[0].statusClass).toBe('error');
+  });
+
+  it('should never let a broken sink break the request', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      metricsSink: {
+        recordRequest: () => {
+          throw new Error('sink exploded');
+        },
+      },
+    });
+
+    const result = await client.get<{ ok: boolean }>('/turbosign/documents');
+
+    expect(result.ok).toBe(true);
+  });
+});
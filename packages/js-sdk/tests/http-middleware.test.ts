@@ -0,0 +1,90 @@
+/**
+ * HTTP Client Middleware Tests
+ *
+ * Tests for the request interceptor chain: mutation, ordering, and
+ * short-circuiting.
+ */
+
+import { HttpClient, Middleware } from '../src/http';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+const makeClient = (middleware: Middleware[]) =>
+  new HttpClient({
+    apiKey: 'test-api-key',
+    orgId: 'test-org-id',
+    senderEmail: 'support@company.com',
+    middleware,
+  });
+
+describe('HttpClient middleware', () => {
+  let mockFetch: jest.Mock;
+
+  beforeEach(() => {
+    mockFetch = jest.fn().mockResolvedValue(okResponse);
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should let middleware mutate outgoing headers', async () => {
+    const signing: Middleware = (request, next) => {
+      request.headers['X-Signature'] = `signed:${request.method}:${request.url}`;
+      return next(request);
+    };
+
+    await makeClient([signing]).get('/turbosign/documents');
+
+    const headers = mockFetch.mock.calls[0][1].headers;
+    expect(headers['X-Signature']).toBe(
+      'signed:GET:https://api.turbodocx.com/turbosign/documents'
+    );
+    // Original auth headers still present
+    expect(headers['Authorization']).toBe('Bearer test-api-key');
+  });
+
+  it('should run middleware in registration order, first outermost', async () => {
+    const order: string[] = [];
+    const outer: Middleware = async (request, next) => {
+      order.push('outer:before');
+      const response = await next(request);
+      order.push('outer:after');
+      return response;
+    };
+    const inner: Middleware = async (request, next) => {
+      order.push('inner:before');
+      const response = await next(request);
+      order.push('inner:after');
+      return response;
+    };
+
+    await makeClient([outer, inner]).get('/turbosign/documents');
+
+    expect(order).toEqual(['outer:before', 'inner:before', 'inner:after', 'outer:after']);
+  });
+
+  it('should allow short-circuiting without hitting the network', async () => {
+    const stub: Middleware = async () => okResponse as unknown as Response;
+
+    const result = await makeClient([stub]).get<{ ok: boolean }>('/turbosign/documents');
+
+    expect(result).toEqual({ ok: true });
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should observe responses on the way out', async () => {
+    const seen: number[] = [];
+    const audit: Middleware = async (request, next) => {
+      const response = await next(request);
+      seen.push(response.status);
+      return response;
+    };
+
+    await makeClient([audit]).get('/turbosign/documents');
+
+    expect(seen).toEqual([200]);
+  });
+});
@@ -0,0 +1,107 @@
+/**
+ * HTTP Client Proxy Tests
+ *
+ * Tests for proxy resolution (explicit config, HTTPS_PROXY/NO_PROXY env
+ * vars) and the dispatcher passthrough to fetch.
+ */
+
+import { HttpClient } from '../src/http';
+import { shouldBypassProxy, resolveProxyUrl, createProxyDispatcher } from '../src/utils/proxy';
+import { ValidationError } from '../src/utils/errors';
+
+describe('shouldBypassProxy', () => {
+  it('should match exact hosts and domain suffixes', () => {
+    expect(shouldBypassProxy('api.turbodocx.com', 'api.turbodocx.com')).toBe(true);
+    expect(shouldBypassProxy('api.turbodocx.com', 'turbodocx.com')).toBe(true);
+    expect(shouldBypassProxy('api.turbodocx.com', '.turbodocx.com')).toBe(true);
+    expect(shouldBypassProxy('api.turbodocx.com', 'localhost,internal.corp')).toBe(false);
+  });
+
+  it('should treat * as a global bypass', () => {
+    expect(shouldBypassProxy('api.turbodocx.com', '*')).toBe(true);
+  });
+
+  it('should not bypass without a NO_PROXY value', () => {
+    expect(shouldBypassProxy('api.turbodocx.com', undefined)).toBe(false);
+    expect(shouldBypassProxy('api.turbodocx.com', '')).toBe(false);
+  });
+});
+
+describe('resolveProxyUrl', () => {
+  beforeEach(() => {
+    delete process.env.HTTPS_PROXY;
+    delete process.env.https_proxy;
+    delete process.env.NO_PROXY;
+    delete process.env.no_proxy;
+  });
+
+  it('should prefer explicit config over env vars', () => {
+    process.env.HTTPS_PROXY = 'http://env-proxy:8080';
+    expect(resolveProxyUrl('http://config-proxy:3128', 'https://api.turbodocx.com')).toBe(
+      'http://config-proxy:3128'
+    );
+  });
+
+  it('should fall back to HTTPS_PROXY', () => {
+    process.env.HTTPS_PROXY = 'http://env-proxy:8080';
+    expect(resolveProxyUrl(undefined, 'https://api.turbodocx.com')).toBe('http://env-proxy:8080');
+  });
+
+  it('should honor NO_PROXY for the API host', () => {
+    process.env.HTTPS_PROXY = 'http://env-proxy:8080';
+    process.env.NO_PROXY = 'turbodocx.com';
+    expect(resolveProxyUrl(undefined, 'https://api.turbodocx.com')).toBeUndefined();
+  });
+
+  it('should return undefined when nothing is configured', () => {
+    expect(resolveProxyUrl(undefined, 'https://api.turbodocx.com')).toBeUndefined();
+  });
+});
+
+describe('createProxyDispatcher', () => {
+  it('should reject SOCKS proxy URLs with guidance', () => {
+    expect(() => createProxyDispatcher('socks5://proxy.corp:1080')).toThrow(ValidationError);
+    expect(() => createProxyDispatcher('socks5://proxy.corp:1080')).toThrow(/custom dispatcher/);
+  });
+});
+
+describe('HttpClient dispatcher passthrough', () => {
+  let mockFetch: jest.Mock;
+
+  beforeEach(() => {
+    delete process.env.HTTPS_PROXY;
+    mockFetch = jest.fn().mockResolvedValue({
+      ok: true,
+      status: 200,
+      headers: { get: () => 'application/json' },
+      json: async () => ({ data: { ok: true } }),
+    });
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should pass a configured dispatcher to fetch', async () => {
+    const dispatcher = { fake: 'dispatcher' };
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      dispatcher,
+    });
+
+    await client.get('/turbosign/documents');
+
+    expect(mockFetch.mock.calls[0][1].dispatcher).toBe(dispatcher);
+  });
+
+  it('should not set a dispatcher when none is configured', async () => {
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+    await client.get('/turbosign/documents');
+
+    expect(mockFetch.mock.calls[0][1].dispatcher).toBeUndefined();
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * HTTP Client Per-Request Options Tests
+ *
+ * Tests for the RequestOptions overrides: per-call org ID, extra headers,
+ * timeout, and retry behavior. Overrides apply to a single call only —
+ * the client-level config is untouched for subsequent requests.
+ */
+
+import { HttpClient } from '../src/http';
+import { TimeoutError } from '../src/utils/errors';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { success: true } }),
+};
+
+describe('HttpClient per-request options', () => {
+  let mockFetch: jest.Mock;
+  let client: HttpClient;
+
+  beforeEach(() => {
+    mockFetch = jest.fn().mockResolvedValue(okResponse);
+    global.fetch = mockFetch as unknown as typeof fetch;
+    client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+  });
+
+  describe('orgId override', () => {
+    it('should send the per-call org ID instead of the configured one', async () => {
+      await client.get('/turbosign/documents/doc-1/status', { orgId: 'tenant-org-2' });
+
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['x-rapiddocx-org-id']).toBe('tenant-org-2');
+    });
+
+    it('should not change the client default for later calls', async () => {
+      await client.get('/turbosign/documents/doc-1/status', { orgId: 'tenant-org-2' });
+      await client.get('/turbosign/documents/doc-2/status');
+
+      const headers = mockFetch.mock.calls[1][1].headers;
+      expect(headers['x-rapiddocx-org-id']).toBe('test-org-id');
+    });
+  });
+
+  describe('extra headers', () => {
+    it('should merge per-call headers over the defaults', async () => {
+      await client.get('/turbosign/documents/doc-1/status', {
+        headers: { 'x-correlation-id': 'corr-123' },
+      });
+
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['x-correlation-id']).toBe('corr-123');
+    });
+
+    it('should keep auth and org headers when extra headers are passed', async () => {
+      await client.get('/turbosign/documents/doc-1/status', {
+        headers: { 'x-correlation-id': 'corr-123' },
+      });
+
+      const headers = mockFetch.mock.calls[0][1].headers;
+      expect(headers['Authorization']).toBe('Bearer test-api-key');
+      expect(headers['x-rapiddocx-org-id']).toBe('test-org-id');
+    });
+  });
+
+  describe('timeoutMs override', () => {
+    it('should time out a single call without a client-level timeout', async () => {
+      mockFetch.mockImplementation((_url: string, init?: RequestInit) => {
+        return new Promise((_resolve, reject) => {
+          init?.signal?.addEventListener('abort', () => {
+            reject(new DOMException('This operation was aborted', 'AbortError'));
+          });
+        });
+      });
+
+      await expect(
+        client.get('/turbosign/documents/doc-1/status', { timeoutMs: 20 })
+      ).rejects.toThrow(TimeoutError);
+    });
+  });
+
+  describe('maxAttempts override', () => {
+    it('should retry a 503 when the per-call maxAttempts allows it', async () => {
+      mockFetch
+        .mockResolvedValueOnce({
+          ok: false,
+          status: 503,
+          headers: { get: () => null },
+          json: async () => ({ message: 'service unavailable' }),
+        })
+        .mockResolvedValueOnce(okResponse);
+
+      const result = await client.get('/turbosign/documents/doc-1/status', {
+        maxAttempts: 2,
+      });
+
+      expect(mockFetch).toHaveBeenCalledTimes(2);
+      expect(result).toEqual({ success: true });
+    });
+
+    it('should not retry when the per-call maxAttempts is 1', async () => {
+      mockFetch.mockResolvedValue({
+        ok: false,
+        status: 503,
+        headers: { get: () => null },
+        json: async () => ({ message: 'service unavailable' }),
+      });
+
+      await expect(
+        client.get('/turbosign/documents/doc-1/status', { maxAttempts: 1 })
+      ).rejects.toThrow();
+      expect(mockFetch).toHaveBeenCalledTimes(1);
+    });
+  });
+});
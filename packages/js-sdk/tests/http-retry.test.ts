@@ -0,0 +1,107 @@
+/**
+ * HTTP Client Retry Tests
+ *
+ * Tests for the opt-in maxAttempts config option: transient failures
+ * (502/503/504, connection errors, timeouts) are retried with backoff,
+ * everything else surfaces immediately.
+ */
+
+import { HttpClient } from '../src/http';
+import { ValidationError, NetworkError } from '../src/utils/errors';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: () => 'application/json' },
+  json: async () => ({ data: { ok: true } }),
+};
+
+const errorResponse = (status: number) => ({
+  ok: false,
+  status,
+  statusText: 'error',
+  headers: { get: () => 'application/json' },
+  json: async () => ({ message: `upstream ${status}` }),
+});
+
+const makeClient = (maxAttempts?: number) =>
+  new HttpClient({
+    apiKey: 'test-api-key',
+    orgId: 'test-org-id',
+    senderEmail: 'support@company.com',
+    maxAttempts,
+  });
+
+describe('HttpClient maxAttempts', () => {
+  let mockFetch: jest.Mock;
+
+  beforeEach(() => {
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should retry 503 responses and succeed once the service recovers', async () => {
+    mockFetch
+      .mockResolvedValueOnce(errorResponse(503))
+      .mockResolvedValueOnce(errorResponse(503))
+      .mockResolvedValueOnce(okResponse);
+
+    const result = await makeClient(3).get<{ ok: boolean }>('/turbosign/documents');
+
+    expect(result).toEqual({ ok: true });
+    expect(mockFetch).toHaveBeenCalledTimes(3);
+  });
+
+  it('should retry connection failures', async () => {
+    mockFetch
+      .mockRejectedValueOnce(new TypeError('fetch failed'))
+      .mockResolvedValueOnce(okResponse);
+
+    const result = await makeClient(2).get<{ ok: boolean }>('/turbosign/documents');
+
+    expect(result).toEqual({ ok: true });
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+  });
+
+  it('should surface the error once attempts are exhausted', async () => {
+    mockFetch.mockRejectedValue(new TypeError('fetch failed'));
+
+    await expect(makeClient(3).get('/turbosign/documents')).rejects.toThrow(NetworkError);
+    expect(mockFetch).toHaveBeenCalledTimes(3);
+  });
+
+  it('should not retry 4xx responses', async () => {
+    mockFetch.mockResolvedValue(errorResponse(400));
+
+    await expect(makeClient(3).get('/turbosign/documents')).rejects.toThrow(ValidationError);
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+  });
+
+  it('should not retry 500 responses', async () => {
+    mockFetch.mockResolvedValue(errorResponse(500));
+
+    await expect(makeClient(3).get('/turbosign/documents')).rejects.toThrow('upstream 500');
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+  });
+
+  it('should make a single attempt by default', async () => {
+    mockFetch.mockResolvedValue(errorResponse(503));
+
+    await expect(makeClient().get('/turbosign/documents')).rejects.toThrow('upstream 503');
+    expect(mockFetch).toHaveBeenCalledTimes(1);
+  });
+
+  it('should apply retries to file uploads', async () => {
+    mockFetch
+      .mockResolvedValueOnce(errorResponse(502))
+      .mockResolvedValueOnce(okResponse);
+
+    const result = await makeClient(2).uploadFile<{ ok: boolean }>(
+      '/turbosign/single/prepare-for-signing',
+      Buffer.from('%PDF-1.4')
+    );
+
+    expect(result).toEqual({ ok: true });
+    expect(mockFetch).toHaveBeenCalledTimes(2);
+  });
+});
@@ -0,0 +1,160 @@
+/**
+ * Graceful Shutdown Tests
+ *
+ * Tests for HttpClient.shutdown: new requests are rejected, in-flight
+ * requests drain within the grace period, and active watch() loops stop at
+ * their next wake-up instead of holding up termination.
+ */
+
+import { HttpClient } from '../src/http';
+import { TurboSignClient } from '../src/modules/sign';
+import { ClientClosedError } from '../src/utils/errors';
+
+describe('HttpClient.shutdown', () => {
+  let mockFetch: jest.Mock;
+
+  const okResponse = {
+    ok: true,
+    status: 200,
+    headers: { get: () => 'application/json' },
+    json: async () => ({ data: { ok: true } }),
+  };
+
+  const makeClient = () =>
+    new HttpClient({
+      apiKey: 'test-key',
+      orgId: 'test-org-id',
+      senderEmail: 'sender@company.com',
+    });
+
+  const tick = () => new Promise((resolve) => setTimeout(resolve, 0));
+
+  beforeEach(() => {
+    mockFetch = jest.fn().mockResolvedValue(okResponse);
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should resolve immediately when nothing is in flight', async () => {
+    await makeClient().shutdown();
+  });
+
+  it('should reject new requests with ClientClosedError after shutdown', async () => {
+    const client = makeClient();
+    await client.shutdown();
+
+    await expect(client.get('/turbosign/documents')).rejects.toBeInstanceOf(ClientClosedError);
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should wait for in-flight requests to settle', async () => {
+    let resolveFetch!: (response: typeof okResponse) => void;
+    mockFetch.mockImplementation(() => new Promise((resolve) => { resolveFetch = resolve; }));
+    const client = makeClient();
+
+    const pending = client.get('/turbosign/documents');
+    let drained = false;
+    const shutdown = client.shutdown(5000).then(() => { drained = true; });
+
+    await tick();
+    expect(drained).toBe(false);
+
+    resolveFetch(okResponse);
+    await pending;
+    await shutdown;
+    expect(drained).toBe(true);
+  });
+
+  it('should give up once the grace period elapses', async () => {
+    mockFetch.mockImplementation(() => new Promise(() => undefined));
+    const client = makeClient();
+
+    void client.get('/turbosign/documents').catch(() => undefined);
+    await client.shutdown(15);
+  });
+
+  it('should be idempotent', async () => {
+    const client = makeClient();
+
+    await client.shutdown();
+    await client.shutdown();
+  });
+
+  it('should share one whenShutdown promise instead of growing a waiter per call', async () => {
+    const client = makeClient();
+
+    const first = client.whenShutdown();
+    const second = client.whenShutdown();
+
+    expect(second).toBe(first);
+    // A watcher polling every few seconds must not leak a resolver per cycle
+    expect((client as any).shutdownWaiters).toHaveLength(1);
+
+    await client.shutdown();
+    await first;
+  });
+});
+
+describe('watch during shutdown', () => {
+  it('should stop an active watch at its next wake-up', async () => {
+    const mockFetch = jest.fn().mockResolvedValue({
+      ok: true,
+      status: 200,
+      headers: { get: () => 'application/json' },
+      json: async () => ({ data: { status: 'under_review' } }),
+    });
+    global.fetch = mockFetch as unknown as typeof fetch;
+
+    const client = new TurboSignClient({
+      apiKey: 'test-key',
+      orgId: 'test-org-id',
+      senderEmail: 'sender@company.com',
+    });
+
+    const statuses: string[] = [];
+    const watching = (async () => {
+      // A long interval: without the shutdown race this would sleep a minute
+      for await (const change of client.watch(['doc-1'], { pollIntervalMs: 60000 })) {
+        statuses.push(change.status);
+      }
+    })();
+
+    // Let the first poll land, then drain
+    await new Promise((resolve) => setTimeout(resolve, 20));
+    await client.shutdown();
+    await watching;
+
+    expect(statuses).toEqual(['under_review']);
+  });
+
+  it('should subscribe to shutdown once per watch, not once per poll cycle', async () => {
+    const mockFetch = jest.fn().mockResolvedValue({
+      ok: true,
+      status: 200,
+      headers: { get: () => 'application/json' },
+      json: async () => ({ data: { status: 'under_review' } }),
+    });
+    global.fetch = mockFetch as unknown as typeof fetch;
+
+    const client = new TurboSignClient({
+      apiKey: 'test-key',
+      orgId: 'test-org-id',
+      senderEmail: 'sender@company.com',
+    });
+    const whenShutdown = jest.spyOn((client as any).client, 'whenShutdown');
+
+    const watching = (async () => {
+      for await (const _change of client.watch(['doc-1'], { pollIntervalMs: 5, maxPollIntervalMs: 5 })) {
+        // Drain status changes; the document never reaches a terminal state
+      }
+    })();
+
+    // Let several poll cycles elapse: each one must not add a reaction to
+    // the shared shutdown promise, or a long-lived watcher grows unbounded
+    await new Promise((resolve) => setTimeout(resolve, 60));
+    await client.shutdown();
+    await watching;
+
+    expect(mockFetch.mock.calls.length).toBeGreaterThan(2);
+    expect(whenShutdown).toHaveBeenCalledTimes(1);
+  });
+});
@@ -0,0 +1,101 @@
+/**
+ * Stream-to-File Tests
+ *
+ * Tests for streamResponseToFile: chunked bodies land on disk intact, the
+ * buffered fallback works without a body stream, and write-stream errors
+ * reject the promise instead of crashing the process or hanging the
+ * backpressure wait.
+ */
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { streamResponseToFile } from '../src/http';
+
+const chunkedBody = (chunks: Buffer[]): ReadableStream<Uint8Array> =>
+  new ReadableStream({
+    start(controller) {
+      for (const chunk of chunks) {
+        controller.enqueue(new Uint8Array(chunk));
+      }
+      controller.close();
+    },
+  });
+
+describe('streamResponseToFile', () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), 'turbodocx-stream-'));
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('should write a chunked body to disk', async () => {
+    const filePath = path.join(tmpDir, 'contract.pdf');
+    const response = {
+      body: chunkedBody([Buffer.from('%PDF-1.7 '), Buffer.from('streamed content')]),
+    } as unknown as Response;
+
+    const result = await streamResponseToFile(response, filePath);
+
+    expect(result).toEqual({ filePath, bytesWritten: 25 });
+    expect(fs.readFileSync(filePath, 'utf8')).toBe('%PDF-1.7 streamed content');
+  });
+
+  it('should fall back to buffering when the runtime provides no body stream', async () => {
+    const filePath = path.join(tmpDir, 'contract.pdf');
+    const body = Buffer.from('%PDF-1.7 buffered');
+    const response = {
+      body: null,
+      arrayBuffer: async () => body.buffer.slice(body.byteOffset, body.byteOffset + body.byteLength),
+    } as unknown as Response;
+
+    const result = await streamResponseToFile(response, filePath);
+
+    expect(result.bytesWritten).toBe(17);
+    expect(fs.readFileSync(filePath, 'utf8')).toBe('%PDF-1.7 buffered');
+  });
+
+  it('should reject on a write-stream error instead of crashing or hanging', async () => {
+    // A missing parent directory fails the stream's open; before the error
+    // listener was attached up front this crashed the process
+    const filePath = path.join(tmpDir, 'no-such-dir', 'contract.pdf');
+    const response = {
+      body: chunkedBody([Buffer.from('%PDF-1.7 doomed')]),
+    } as unknown as Response;
+
+    await expect(streamResponseToFile(response, filePath)).rejects.toThrow(/ENOENT/);
+  });
+
+  it('should destroy the write stream when the body fails mid-download', async () => {
+    const filePath = path.join(tmpDir, 'contract.pdf');
+    // One good chunk, then the connection drops
+    const response = {
+      body: new ReadableStream<Uint8Array>({
+        start(controller) {
+          controller.enqueue(new Uint8Array(Buffer.from('%PDF-1.7 partial')));
+          controller.error(new Error('connection reset'));
+        },
+      }),
+    } as unknown as Response;
+
+    const realCreateWriteStream = fs.createWriteStream;
+    let stream!: fs.WriteStream;
+    const spy = jest.spyOn(fs, 'createWriteStream').mockImplementation(((target: fs.PathLike) => {
+      stream = realCreateWriteStream(target);
+      return stream;
+    }) as typeof fs.createWriteStream);
+
+    try {
+      await expect(streamResponseToFile(response, filePath)).rejects.toThrow('connection reset');
+      // The descriptor must be closed on the failure path — a long-running
+      // worker retrying large downloads would otherwise leak one per failure
+      expect(stream.destroyed).toBe(true);
+    } finally {
+      spy.mockRestore();
+    }
+  });
+});
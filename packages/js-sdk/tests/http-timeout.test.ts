@@ -0,0 +1,86 @@
+/**
+ * HTTP Client Timeout Tests
+ *
+ * Tests for the timeoutMs config option: requests that exceed the timeout
+ * are aborted and surface a TimeoutError; clients without a timeout keep
+ * the previous no-timeout behavior.
+ */
+
+import { HttpClient } from '../src/http';
+import { TimeoutError, NetworkError } from '../src/utils/errors';
+
+// fetch stub that hangs until its abort signal fires, then rejects the way
+// undici does when a request is aborted
+const hangingFetch = jest.fn((_url: string, init?: RequestInit) => {
+  return new Promise<Response>((_resolve, reject) => {
+    init?.signal?.addEventListener('abort', () => {
+      reject(new DOMException('This operation was aborted', 'AbortError'));
+    });
+  });
+});
+
+describe('HttpClient timeoutMs', () => {
+  let client: HttpClient;
+
+  beforeEach(() => {
+    global.fetch = hangingFetch as unknown as typeof fetch;
+    hangingFetch.mockClear();
+    client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      timeoutMs: 20,
+    });
+  });
+
+  it('should throw TimeoutError when a request exceeds timeoutMs', async () => {
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(TimeoutError);
+  });
+
+  it('should include the configured timeout in the error message', async () => {
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(
+      'Request timed out after 20ms'
+    );
+  });
+
+  it('should remain catchable as NetworkError for existing callers', async () => {
+    let caught: unknown;
+    try {
+      await client.get('/turbosign/documents');
+    } catch (error) {
+      caught = error;
+    }
+    expect(caught).toBeInstanceOf(NetworkError);
+    expect((caught as TimeoutError).code).toBe('TIMEOUT');
+  });
+
+  it('should pass an abort signal to fetch when timeoutMs is set', async () => {
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(TimeoutError);
+    const init = hangingFetch.mock.calls[0][1];
+    expect(init?.signal).toBeInstanceOf(AbortSignal);
+  });
+
+  it('should not pass an abort signal when no timeout is configured', async () => {
+    const untimed = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+    global.fetch = jest.fn().mockResolvedValue({
+      ok: true,
+      headers: { get: () => 'application/json' },
+      json: async () => ({ data: { ok: true } }),
+    }) as unknown as typeof fetch;
+
+    await untimed.get('/turbosign/documents');
+    const init = (global.fetch as jest.Mock).mock.calls[0][1];
+    expect(init.signal).toBeUndefined();
+  });
+
+  it('should apply the timeout to file uploads', async () => {
+    await expect(
+      client.uploadFile('/turbosign/single/prepare-for-signing', Buffer.from('%PDF-1.4'))
+    ).rejects.toThrow(TimeoutError);
+  });
+});
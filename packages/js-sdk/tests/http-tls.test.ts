@@ -0,0 +1,90 @@
+/**
+ * HTTP Client TLS Trust Tests
+ *
+ * Tests for custom root CA configuration and the dev-only verification
+ * opt-out, using a virtual undici mock since the package is optional.
+ */
+
+// undici is an optional dependency, so mock it virtually
+const MockAgent = jest.fn();
+jest.mock('undici', () => ({ Agent: MockAgent }), { virtual: true });
+
+import { HttpClient } from '../src/http';
+import { createTlsDispatcher } from '../src/utils/tls';
+
+const FAKE_PEM = '-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----';
+
+describe('createTlsDispatcher', () => {
+  beforeEach(() => {
+    MockAgent.mockClear();
+  });
+
+  it('should pass root certificates as connect CA options', () => {
+    createTlsDispatcher({ rootCertificates: [FAKE_PEM] });
+
+    expect(MockAgent).toHaveBeenCalledWith({ connect: { ca: [FAKE_PEM] } });
+  });
+
+  it('should disable verification only when explicitly opted in', () => {
+    createTlsDispatcher({ insecureSkipTlsVerify: true });
+
+    expect(MockAgent).toHaveBeenCalledWith({ connect: { rejectUnauthorized: false } });
+  });
+
+  it('should pass a client certificate for mutual TLS', () => {
+    createTlsDispatcher({
+      clientCertificate: { cert: FAKE_PEM, key: 'fake-key', passphrase: 'secret' },
+    });
+
+    expect(MockAgent).toHaveBeenCalledWith({
+      connect: { cert: FAKE_PEM, key: 'fake-key', passphrase: 'secret' },
+    });
+  });
+
+  it('should omit the passphrase when the key is unencrypted', () => {
+    createTlsDispatcher({
+      clientCertificate: { cert: FAKE_PEM, key: 'fake-key' },
+    });
+
+    expect(MockAgent).toHaveBeenCalledWith({
+      connect: { cert: FAKE_PEM, key: 'fake-key' },
+    });
+  });
+});
+
+describe('HttpClient TLS config', () => {
+  beforeEach(() => {
+    MockAgent.mockClear();
+  });
+
+  it('should build a TLS dispatcher from rootCertificates', async () => {
+    const mockFetch = jest.fn().mockResolvedValue({
+      ok: true,
+      status: 200,
+      headers: { get: () => 'application/json' },
+      json: async () => ({ data: { ok: true } }),
+    });
+    global.fetch = mockFetch as unknown as typeof fetch;
+
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      rootCertificates: [FAKE_PEM],
+    });
+    await client.get('/turbosign/documents');
+
+    expect(MockAgent).toHaveBeenCalledTimes(1);
+    expect(mockFetch.mock.calls[0][1].dispatcher).toBe(MockAgent.mock.instances[0]);
+  });
+
+  it('should not build a dispatcher without TLS settings', () => {
+    new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+    expect(MockAgent).not.toHaveBeenCalled();
+  });
+});
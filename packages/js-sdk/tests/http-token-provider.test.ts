@@ -0,0 +1,86 @@
+/**
+ * Token Provider Tests
+ *
+ * Tests for the tokenProvider config: the client asks the provider for a
+ * fresh bearer token before each request, so credentials rotated outside
+ * the process (e.g. short-lived Vault tokens) stay current without
+ * reconfiguring the client.
+ */
+
+import { HttpClient } from '../src/http';
+import { AuthenticationError } from '../src/utils/errors';
+
+describe('HttpClient tokenProvider', () => {
+  let mockFetch: jest.Mock;
+
+  const okResponse = {
+    ok: true,
+    status: 200,
+    headers: { get: () => 'application/json' },
+    json: async () => ({ data: { ok: true } }),
+  };
+
+  const makeClient = (tokenProvider: () => string | Promise<string>) =>
+    new HttpClient({
+      tokenProvider,
+      orgId: 'test-org-id',
+      senderEmail: 'sender@company.com',
+    });
+
+  const sentAuthorization = (call: number): string =>
+    mockFetch.mock.calls[call][1].headers['Authorization'];
+
+  beforeEach(() => {
+    mockFetch = jest.fn().mockResolvedValue(okResponse);
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should accept a tokenProvider as the only credential', () => {
+    expect(() => makeClient(() => 'vault-token')).not.toThrow();
+  });
+
+  it('should still require some credential', () => {
+    expect(() => {
+      new HttpClient({ orgId: 'test-org-id', senderEmail: 'sender@company.com' });
+    }).toThrow(AuthenticationError);
+  });
+
+  it('should ask the provider before each request and send its token', async () => {
+    const tokens = ['token-1', 'token-2'];
+    const provider = jest.fn(() => tokens.shift()!);
+    const client = makeClient(provider);
+
+    await client.get('/turbosign/documents');
+    await client.get('/turbosign/documents');
+
+    expect(provider).toHaveBeenCalledTimes(2);
+    expect(sentAuthorization(0)).toBe('Bearer token-1');
+    expect(sentAuthorization(1)).toBe('Bearer token-2');
+  });
+
+  it('should support async providers', async () => {
+    const client = makeClient(async () => 'async-token');
+
+    await client.get('/turbosign/documents');
+
+    expect(sentAuthorization(0)).toBe('Bearer async-token');
+  });
+
+  it('should surface provider failures as AuthenticationError', async () => {
+    const client = makeClient(() => {
+      throw new Error('vault sealed');
+    });
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(AuthenticationError);
+    await expect(client.get('/turbosign/documents')).rejects.toThrow('vault sealed');
+    expect(mockFetch).not.toHaveBeenCalled();
+  });
+
+  it('should reject empty tokens', async () => {
+    const client = makeClient(() => '  ');
+
+    await expect(client.get('/turbosign/documents')).rejects.toThrow(
+      'tokenProvider returned an empty token'
+    );
+  });
+});
@@ -0,0 +1,147 @@
+/**
+ * HTTP Client Tracing Tests
+ *
+ * Tests for the tracer integration: one span per logical request covering
+ * all retry attempts, with retries and errors recorded as span events, and
+ * tracer failures never breaking a request.
+ */
+
+import { HttpClient } from '../src/http';
+import type { TraceAttributeValue } from '../src/utils/tracing';
+
+const okResponse = {
+  ok: true,
+  status: 200,
+  headers: { get: (name: string) => (name === 'x-request-id' ? 'req-abc-123' : 'application/json') },
+  json: async () => ({ data: { ok: true } }),
+};
+
+interface RecordedSpan {
+  name: string;
+  attributes: Record<string, TraceAttributeValue>;
+  events: Array<{ name: string; attributes?: Record<string, TraceAttributeValue> }>;
+  exceptions: unknown[];
+  status?: { code: number; message?: string };
+  ended: boolean;
+}
+
+describe('HttpClient tracing', () => {
+  let mockFetch: jest.Mock;
+  let spans: RecordedSpan[];
+
+  const tracer = {
+    startSpan: (name: string, options?: { attributes?: Record<string, TraceAttributeValue> }) => {
+      const span: RecordedSpan = {
+        name,
+        attributes: { ...options?.attributes },
+        events: [],
+        exceptions: [],
+        ended: false,
+      };
+      spans.push(span);
+      return {
+        setAttribute: (key: string, value: TraceAttributeValue) => {
+          span.attributes[key] = value;
+        },
+        addEvent: (eventName: string, attributes?: Record<string, TraceAttributeValue>) => {
+          span.events.push({ name: eventName, attributes });
+        },
+        recordException: (error: unknown) => {
+          span.exceptions.push(error);
+        },
+        setStatus: (status: { code: number; message?: string }) => {
+          span.status = status;
+        },
+        end: () => {
+          span.ended = true;
+        },
+      };
+    },
+  };
+
+  const makeClient = (maxAttempts?: number) =>
+    new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      maxAttempts,
+      tracer,
+    });
+
+  beforeEach(() => {
+    spans = [];
+    mockFetch = jest.fn();
+    global.fetch = mockFetch as unknown as typeof fetch;
+  });
+
+  it('should record one span with method, path, status, and request id', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+
+    await makeClient().get('/turbosign/documents');
+
+    expect(spans).toHaveLength(1);
+    expect(spans[0].name).toBe('GET /turbosign/documents');
+    expect(spans[0].attributes['http.request.method']).toBe('GET');
+    expect(spans[0].attributes['url.path']).toBe('/turbosign/documents');
+    expect(spans[0].attributes['http.response.status_code']).toBe(200);
+    expect(spans[0].attributes['turbodocx.request_id']).toBe('req-abc-123');
+    expect(spans[0].ended).toBe(true);
+  });
+
+  it('should keep retry attempts inside one span with retry events', async () => {
+    mockFetch
+      .mockResolvedValueOnce({ ...okResponse, ok: false, status: 503 })
+      .mockResolvedValueOnce(okResponse);
+
+    await makeClient(2).get('/turbosign/documents');
+
+    expect(spans).toHaveLength(1);
+    expect(spans[0].events).toEqual([
+      { name: 'retry', attributes: { attempt: 1, 'http.response.status_code': 503 } },
+    ]);
+    expect(spans[0].attributes['http.response.status_code']).toBe(200);
+  });
+
+  it('should record the exception and error status on failure', async () => {
+    const failure = new TypeError('fetch failed');
+    mockFetch.mockRejectedValue(failure);
+
+    await expect(makeClient().get('/turbosign/documents')).rejects.toThrow();
+
+    expect(spans).toHaveLength(1);
+    expect(spans[0].exceptions).toHaveLength(1);
+    expect(spans[0].status?.code).toBe(2);
+    expect(spans[0].ended).toBe(true);
+  });
+
+  it('should never let a broken tracer break the request', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      tracer: {
+        startSpan: () => {
+          throw new Error('tracer exploded');
+        },
+      },
+    });
+
+    const result = await client.get<{ ok: boolean }>('/turbosign/documents');
+
+    expect(result.ok).toBe(true);
+  });
+
+  it('should not create spans without a tracer', async () => {
+    mockFetch.mockResolvedValue(okResponse);
+    const client = new HttpClient({
+      apiKey: 'test-api-key',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+    await client.get('/turbosign/documents');
+
+    expect(spans).toHaveLength(0);
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * Multipart Upload Tests
+ *
+ * Tests for the multipart form assembly in HttpClient.uploadFile
+ */
+
+import { HttpClient } from '../src/http';
+
+// %PDF magic bytes so file type detection resolves to application/pdf
+const pdfBuffer = Buffer.from('%PDF-1.7 test content');
+
+function makeClient(): HttpClient {
+  return new HttpClient({
+    apiKey: 'test-key',
+    orgId: 'org-1',
+    skipSenderValidation: true,
+  });
+}
+
+describe('HttpClient.uploadFile multipart assembly', () => {
+  let capturedBody: FormData;
+
+  beforeEach(() => {
+    global.fetch = jest.fn().mockImplementation((_url, init) => {
+      capturedBody = init.body as FormData;
+      return Promise.resolve({
+        ok: true,
+        json: () => Promise.resolve({ data: { ok: true } }),
+      });
+    }) as jest.Mock;
+  });
+
+  it('should serialize object form values to JSON exactly once', async () => {
+    const recipients = [
+      { name: 'John Doe', email: 'john@example.com', signingOrder: 1 },
+    ];
+
+    await makeClient().uploadFile('/turbosign/single/send', pdfBuffer, 'file', {
+      recipients,
+    });
+
+    // Byte-identical to a single JSON.stringify — no double encoding
+    expect(capturedBody.get('recipients')).toBe(JSON.stringify(recipients));
+  });
+
+  it('should pass pre-serialized string values through unchanged', async () => {
+    const recipientsJson = JSON.stringify([
+      { name: 'Jane Smith', email: 'jane@example.com', signingOrder: 1 },
+    ]);
+
+    await makeClient().uploadFile('/turbosign/single/send', pdfBuffer, 'file', {
+      recipients: recipientsJson,
+      senderEmail: 'sender@example.com',
+    });
+
+    expect(capturedBody.get('recipients')).toBe(recipientsJson);
+    expect(capturedBody.get('senderEmail')).toBe('sender@example.com');
+  });
+
+  it('should attach the file with detected mime type and not leak fileName as a form field', async () => {
+    await makeClient().uploadFile('/turbosign/single/send', pdfBuffer, 'file', {
+      fileName: 'contract.pdf',
+      senderEmail: 'sender@example.com',
+    });
+
+    const file = capturedBody.get('file') as File;
+    expect(file.name).toBe('contract.pdf');
+    expect(file.type).toBe('application/pdf');
+    expect(capturedBody.get('fileName')).toBeNull();
+  });
+});
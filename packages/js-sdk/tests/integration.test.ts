@@ -0,0 +1,241 @@
+/**
+ * Integration Tests — local stub server
+ *
+ * Unlike the unit suites, these tests do NOT mock fetch or HttpClient.
+ * A real in-process HTTP server records every request, so endpoint paths,
+ * headers, JSON bodies, and multipart assembly are verified end to end.
+ * Runs fully offline.
+ */
+
+import * as http from 'http';
+import { AddressInfo } from 'net';
+import { TurboSign } from '../src/modules/sign';
+import { AuthenticationError, ConflictError, NotFoundError, ValidationError } from '../src/utils/errors';
+
+interface RecordedRequest {
+  method: string;
+  url: string;
+  headers: http.IncomingHttpHeaders;
+  body: Buffer;
+}
+
+let server: http.Server;
+let baseUrl: string;
+let recorded: RecordedRequest[];
+let nextStatus: number;
+let nextBody: unknown;
+let nextHeaders: Record<string, string>;
+
+function respondWith(status: number, body: unknown, headers: Record<string, string> = {}): void {
+  nextStatus = status;
+  nextBody = body;
+  nextHeaders = headers;
+}
+
+beforeAll((done) => {
+  server = http.createServer((req, res) => {
+    const chunks: Buffer[] = [];
+    req.on('data', (chunk) => chunks.push(chunk));
+    req.on('end', () => {
+      recorded.push({
+        method: req.method ?? '',
+        url: req.url ?? '',
+        headers: req.headers,
+        body: Buffer.concat(chunks),
+      });
+      res.writeHead(nextStatus, { 'Content-Type': 'application/json', ...nextHeaders });
+      res.end(JSON.stringify(nextBody));
+    });
+  });
+  server.listen(0, '127.0.0.1', () => {
+    const { port } = server.address() as AddressInfo;
+    baseUrl = `http://127.0.0.1:${port}`;
+    done();
+  });
+});
+
+afterAll((done) => {
+  server.close(() => done());
+});
+
+beforeEach(() => {
+  recorded = [];
+  respondWith(200, { data: { success: true } });
+  (TurboSign as any).client = undefined;
+  TurboSign.configure({
+    apiKey: 'integration-key',
+    orgId: 'integration-org',
+    senderEmail: 'sender@example.com',
+    baseUrl,
+  });
+});
+
+describe('TurboSign against a stub server', () => {
+  it('should send auth and org headers on every request', async () => {
+    respondWith(200, { data: { status: 'sent' } });
+
+    await TurboSign.getStatus('doc-1');
+
+    expect(recorded).toHaveLength(1);
+    expect(recorded[0].method).toBe('GET');
+    expect(recorded[0].url).toBe('/turbosign/documents/doc-1/status');
+    expect(recorded[0].headers['authorization']).toBe('Bearer integration-key');
+    expect(recorded[0].headers['x-rapiddocx-org-id']).toBe('integration-org');
+    expect(recorded[0].headers['user-agent']).toMatch(/^turbodocx-js-sdk\//);
+  });
+
+  it('should unwrap { data: ... } responses', async () => {
+    respondWith(200, { data: { status: 'completed' } });
+
+    const result = await TurboSign.getStatus('doc-1');
+
+    expect(result).toEqual({ status: 'completed' });
+  });
+
+  it('should post a JSON body for link-based documents', async () => {
+    respondWith(200, {
+      data: { success: true, documentId: 'doc-2', status: 'ready', message: 'ok' },
+    });
+
+    await TurboSign.createSignatureReviewLink({
+      fileLink: 'https://storage.example.com/contract.pdf',
+      recipients: [{ name: 'John Doe', email: 'john@example.com', signingOrder: 1 }],
+      fields: [
+        {
+          type: 'signature',
+          page: 1,
+          x: 100,
+          y: 500,
+          width: 200,
+          height: 50,
+          recipientEmail: 'john@example.com',
+        },
+      ],
+    });
+
+    expect(recorded[0].url).toBe('/turbosign/single/prepare-for-review');
+    expect(recorded[0].headers['content-type']).toBe('application/json');
+
+    const body = JSON.parse(recorded[0].body.toString());
+    expect(body.fileLink).toBe('https://storage.example.com/contract.pdf');
+    expect(JSON.parse(body.recipients)).toHaveLength(1);
+    expect(body.senderEmail).toBe('sender@example.com');
+  });
+
+  it('should post multipart form data for buffer uploads', async () => {
+    respondWith(200, {
+      data: { success: true, documentId: 'doc-3', status: 'sent', message: 'ok' },
+    });
+
+    await TurboSign.sendSignature({
+      file: Buffer.from('%PDF-1.7 integration fixture'),
+      fileName: 'contract.pdf',
+      recipients: [{ name: 'John Doe', email: 'john@example.com', signingOrder: 1 }],
+      fields: [
+        {
+          type: 'signature',
+          page: 1,
+          x: 100,
+          y: 500,
+          width: 200,
+          height: 50,
+          recipientEmail: 'john@example.com',
+        },
+      ],
+    });
+
+    const contentType = recorded[0].headers['content-type'] ?? '';
+    expect(contentType).toMatch(/^multipart\/form-data; boundary=/);
+
+    const raw = recorded[0].body.toString('latin1');
+    expect(raw).toContain('name="file"');
+    expect(raw).toContain('filename="contract.pdf"');
+    expect(raw).toContain('%PDF-1.7 integration fixture');
+    expect(raw).toContain('name="recipients"');
+    expect(raw).toContain('john@example.com');
+  });
+
+  it('should map HTTP error statuses to typed errors', async () => {
+    respondWith(401, { message: 'invalid api key' });
+    await expect(TurboSign.getStatus('doc-1')).rejects.toThrow(AuthenticationError);
+
+    respondWith(404, { message: 'document not found' });
+    await expect(TurboSign.getStatus('missing')).rejects.toThrow(NotFoundError);
+
+    respondWith(409, { message: 'document is at version 5, expected 4' });
+    await expect(TurboSign.void('doc-1', 'stale', { expectedVersion: 4 })).rejects.toThrow(
+      ConflictError
+    );
+  });
+
+  it('should expose status, headers, and request ID via getStatusWithResponse', async () => {
+    respondWith(
+      200,
+      { data: { status: 'completed' } },
+      { 'x-request-id': 'req-meta-1', 'x-ratelimit-remaining': '42' }
+    );
+
+    const response = await TurboSign.getStatusWithResponse('doc-1');
+
+    expect(response.data).toEqual({ status: 'completed' });
+    expect(response.status).toBe(200);
+    expect(response.headers['x-ratelimit-remaining']).toBe('42');
+    expect(response.requestId).toBe('req-meta-1');
+  });
+
+  it('should carry the structured API error body on typed errors', async () => {
+    respondWith(400, {
+      code: 'INVALID_RECIPIENT',
+      message: 'recipient email is malformed',
+      details: { field: 'recipients[0].email' },
+      requestId: 'req-abc-123',
+    });
+
+    const error = await TurboSign.getStatus('doc-1').catch((e) => e);
+
+    expect(error).toBeInstanceOf(ValidationError);
+    expect(error.message).toContain('recipient email is malformed');
+    expect(error.apiError).toEqual({
+      code: 'INVALID_RECIPIENT',
+      message: 'recipient email is malformed',
+      details: { field: 'recipients[0].email' },
+      requestId: 'req-abc-123',
+    });
+  });
+
+  it('should carry x-request-id on errors for support tickets', async () => {
+    respondWith(404, { message: 'document not found' }, { 'x-request-id': 'req-err-42' });
+
+    const error = await TurboSign.getStatus('missing').catch((e) => e);
+
+    expect(error).toBeInstanceOf(NotFoundError);
+    expect(error.requestId).toBe('req-err-42');
+  });
+
+  it('should surface x-request-id on successes via the onResponse hook', async () => {
+    const events: Array<{ requestId?: string }> = [];
+    (TurboSign as any).client = undefined;
+    TurboSign.configure({
+      apiKey: 'integration-key',
+      orgId: 'integration-org',
+      senderEmail: 'sender@example.com',
+      baseUrl,
+      onResponse: (event) => events.push(event),
+    });
+    respondWith(200, { data: { status: 'completed' } }, { 'x-request-id': 'req-ok-7' });
+
+    await TurboSign.getStatus('doc-1');
+
+    expect(events).toHaveLength(1);
+    expect(events[0].requestId).toBe('req-ok-7');
+  });
+
+  it('should fall back to status text when the error body is not an object', async () => {
+    respondWith(500, 'upstream exploded');
+
+    const error = await TurboSign.getStatus('doc-1').catch((e) => e);
+
+    expect(error.message).toContain('HTTP 500');
+    expect(error.apiError).toBeUndefined();
+  });
+});
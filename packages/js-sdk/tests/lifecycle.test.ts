@@ -0,0 +1,74 @@
+/**
+ * Lifecycle State Machine Tests
+ *
+ * Tests for the document and deliverable transition tables and their
+ * canTransition/isTerminal helpers: allowed transitions pass, terminal
+ * statuses refuse everything, and unknown statuses stay permissive.
+ */
+
+import {
+  canDeliverableTransition,
+  canDocumentTransition,
+  isTerminalDeliverableStatus,
+  isTerminalDocumentStatus,
+} from '../src/utils/lifecycle';
+
+describe('document lifecycle', () => {
+  it('should allow the happy path from draft to completed', () => {
+    expect(canDocumentTransition('draft', 'sent')).toBe(true);
+    expect(canDocumentTransition('sent', 'under_review')).toBe(true);
+    expect(canDocumentTransition('under_review', 'completed')).toBe(true);
+  });
+
+  it('should allow voiding an in-flight document', () => {
+    expect(canDocumentTransition('draft', 'voided')).toBe(true);
+    expect(canDocumentTransition('sent', 'voided')).toBe(true);
+    expect(canDocumentTransition('under_review', 'voided')).toBe(true);
+  });
+
+  it('should refuse any transition out of a terminal status', () => {
+    for (const status of ['completed', 'voided', 'declined', 'expired']) {
+      expect(canDocumentTransition(status, 'voided')).toBe(false);
+      expect(canDocumentTransition(status, 'sent')).toBe(false);
+    }
+  });
+
+  it('should refuse skipping back to draft', () => {
+    expect(canDocumentTransition('sent', 'draft')).toBe(false);
+  });
+
+  it('should mark exactly the terminal statuses as terminal', () => {
+    expect(isTerminalDocumentStatus('completed')).toBe(true);
+    expect(isTerminalDocumentStatus('voided')).toBe(true);
+    expect(isTerminalDocumentStatus('declined')).toBe(true);
+    expect(isTerminalDocumentStatus('expired')).toBe(true);
+    expect(isTerminalDocumentStatus('sent')).toBe(false);
+    expect(isTerminalDocumentStatus('under_review')).toBe(false);
+  });
+
+  it('should treat unknown statuses permissively', () => {
+    expect(canDocumentTransition('awaiting_notarization', 'completed')).toBe(true);
+    expect(isTerminalDocumentStatus('awaiting_notarization')).toBe(false);
+  });
+});
+
+describe('deliverable lifecycle', () => {
+  it('should allow generating to resolve to ready, failed, or cancelled', () => {
+    expect(canDeliverableTransition('generating', 'ready')).toBe(true);
+    expect(canDeliverableTransition('generating', 'failed')).toBe(true);
+    expect(canDeliverableTransition('generating', 'cancelled')).toBe(true);
+  });
+
+  it('should only allow archiving a ready deliverable', () => {
+    expect(canDeliverableTransition('ready', 'archived')).toBe(true);
+    expect(canDeliverableTransition('generating', 'archived')).toBe(false);
+  });
+
+  it('should mark resolved generation outcomes as terminal', () => {
+    expect(isTerminalDeliverableStatus('archived')).toBe(true);
+    expect(isTerminalDeliverableStatus('failed')).toBe(true);
+    expect(isTerminalDeliverableStatus('cancelled')).toBe(true);
+    expect(isTerminalDeliverableStatus('generating')).toBe(false);
+    expect(isTerminalDeliverableStatus('ready')).toBe(false);
+  });
+});
@@ -0,0 +1,75 @@
+/**
+ * Pacer Tests
+ *
+ * Tests for the token-bucket pacer: burst capacity passes immediately,
+ * sustained load is held to the configured rate, tokens refill over time,
+ * and bad options fail up front.
+ */
+
+import { Pacer } from '../src/utils/pacer';
+import { ValidationError } from '../src/utils/errors';
+
+describe('Pacer', () => {
+  it('should let burst capacity through without waiting', async () => {
+    const pacer = new Pacer({ ratePerSecond: 1, burst: 3 });
+    const startedAt = Date.now();
+
+    await pacer.acquire();
+    await pacer.acquire();
+    await pacer.acquire();
+
+    expect(Date.now() - startedAt).toBeLessThan(100);
+  });
+
+  it('should wait for a token once the burst is spent', async () => {
+    const pacer = new Pacer({ ratePerSecond: 20 });
+    await pacer.acquire();
+    const startedAt = Date.now();
+
+    await pacer.acquire();
+
+    // 20/s means ~50ms per token; allow timer slop downward
+    expect(Date.now() - startedAt).toBeGreaterThanOrEqual(40);
+  });
+
+  it('should refill tokens over time up to the burst cap', async () => {
+    const pacer = new Pacer({ ratePerSecond: 100, burst: 2 });
+    await pacer.acquire();
+    await pacer.acquire();
+    expect(pacer.available()).toBeLessThan(1);
+
+    await new Promise((resolve) => setTimeout(resolve, 50));
+
+    expect(pacer.available()).toBe(2);
+  });
+
+  it('should add jitter without exceeding the configured bound by much', async () => {
+    const pacer = new Pacer({ ratePerSecond: 1000, burst: 1, jitterMs: 30 });
+    await pacer.acquire();
+    const startedAt = Date.now();
+
+    await pacer.acquire();
+
+    expect(Date.now() - startedAt).toBeLessThan(150);
+  });
+
+  it('should reject a non-positive rate', () => {
+    expect(() => new Pacer({ ratePerSecond: 0 })).toThrow(ValidationError);
+    expect(() => new Pacer({ ratePerSecond: -5 })).toThrow('ratePerSecond');
+  });
+
+  it('should reject a string rate from a config file', () => {
+    expect(() => new Pacer({ ratePerSecond: '10' as unknown as number })).toThrow(
+      ValidationError
+    );
+  });
+
+  it('should reject a fractional or zero burst', () => {
+    expect(() => new Pacer({ ratePerSecond: 10, burst: 0 })).toThrow('burst');
+    expect(() => new Pacer({ ratePerSecond: 10, burst: 1.5 })).toThrow('burst');
+  });
+
+  it('should reject a negative jitter', () => {
+    expect(() => new Pacer({ ratePerSecond: 10, jitterMs: -1 })).toThrow('jitterMs');
+  });
+});
@@ -0,0 +1,77 @@
+/**
+ * PDF Verification Tests
+ *
+ * Tests for offline tamper-evidence checks on signed PDFs
+ */
+
+import { verifySignedPdf } from '../src/utils/pdf-verify';
+
+/**
+ * Build a minimal PDF-shaped buffer with a signature dictionary whose
+ * ByteRange matches the actual layout.
+ */
+function buildSignedPdf(options: { trailingData?: string } = {}): Buffer {
+  const head = '%PDF-1.7\nhead content\n';
+  const contents = '<0000>';
+  const placeholder =
+    '/Type /Sig /SubFilter /adbe.pkcs7.detached /Name (John Doe) /M (D:20260126120000Z) /ByteRange [0 AAAA BBBB CCCC] /Contents ';
+  const tail = '\ntrailer\n%%EOF\n';
+
+  // Assemble once with dummy numbers to learn the offsets, then patch them in
+  // with fixed-width fields so the layout doesn't shift
+  const beforeContents = head + placeholder;
+  const firstLength = beforeContents.length;
+  const secondOffset = firstLength + contents.length;
+  const secondLength = tail.length;
+
+  let body =
+    beforeContents.replace('AAAA', String(firstLength).padStart(4, '0'))
+      .replace('BBBB', String(secondOffset).padStart(4, '0'))
+      .replace('CCCC', String(secondLength).padStart(4, '0')) +
+    contents +
+    tail;
+
+  if (options.trailingData) {
+    body += options.trailingData;
+  }
+  return Buffer.from(body, 'latin1');
+}
+
+describe('verifySignedPdf', () => {
+  it('should reject non-PDF data', () => {
+    const report = verifySignedPdf(Buffer.from('not a pdf'));
+    expect(report.isPdf).toBe(false);
+    expect(report.ok).toBe(false);
+    expect(report.warnings[0]).toMatch(/not a PDF/);
+  });
+
+  it('should report a PDF without signatures', () => {
+    const report = verifySignedPdf(Buffer.from('%PDF-1.7\nno signature here\n%%EOF'));
+    expect(report.isPdf).toBe(true);
+    expect(report.signatureCount).toBe(0);
+    expect(report.ok).toBe(false);
+    expect(report.warnings[0]).toMatch(/No digital signature/);
+  });
+
+  it('should accept an intact signed PDF', () => {
+    const report = verifySignedPdf(buildSignedPdf());
+    expect(report.isPdf).toBe(true);
+    expect(report.signatureCount).toBe(1);
+    expect(report.signatures[0]).toMatchObject({
+      subFilter: 'adbe.pkcs7.detached',
+      signerName: 'John Doe',
+      byteRangeValid: true,
+      coversWholeDocument: true,
+    });
+    expect(report.trailingDataAfterSignature).toBe(false);
+    expect(report.ok).toBe(true);
+  });
+
+  it('should flag data appended after the signed range', () => {
+    const report = verifySignedPdf(buildSignedPdf({ trailingData: 'sneaky edit' }));
+    expect(report.signatureCount).toBe(1);
+    expect(report.trailingDataAfterSignature).toBe(true);
+    expect(report.ok).toBe(false);
+    expect(report.warnings.join(' ')).toMatch(/modified after signing/);
+  });
+});
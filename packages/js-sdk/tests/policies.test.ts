@@ -0,0 +1,83 @@
+/**
+ * Policy Normalization Tests
+ *
+ * Tests for defaults and validation of watch, bulk-send, and retry
+ * policies — the option objects most often loaded from config files.
+ */
+
+import { resolveBulkSendOptions, resolveWatchOptions, validateRetryPolicy } from '../src/utils/policies';
+import { ValidationError } from '../src/utils/errors';
+
+describe('resolveWatchOptions', () => {
+  it('should apply the documented defaults', () => {
+    expect(resolveWatchOptions()).toEqual({
+      pollIntervalMs: 5000,
+      maxPollIntervalMs: 60000,
+      stopAtTerminal: true,
+    });
+  });
+
+  it('should keep explicit values', () => {
+    const resolved = resolveWatchOptions({ pollIntervalMs: 1000, stopAtTerminal: false });
+
+    expect(resolved.pollIntervalMs).toBe(1000);
+    expect(resolved.stopAtTerminal).toBe(false);
+  });
+
+  it('should raise the backoff ceiling to match a slow poll interval', () => {
+    expect(resolveWatchOptions({ pollIntervalMs: 120000 }).maxPollIntervalMs).toBe(120000);
+  });
+
+  it('should reject a non-numeric interval from a config file', () => {
+    expect(() => resolveWatchOptions({ pollIntervalMs: '5000' as unknown as number })).toThrow(
+      ValidationError
+    );
+  });
+
+  it('should reject a ceiling below the poll interval', () => {
+    expect(() => resolveWatchOptions({ pollIntervalMs: 5000, maxPollIntervalMs: 1000 })).toThrow(
+      'must be >= pollIntervalMs'
+    );
+  });
+});
+
+describe('resolveBulkSendOptions', () => {
+  it('should default pacingMs to 500', () => {
+    expect(resolveBulkSendOptions({ templateId: 'tpl-1' }).pacingMs).toBe(500);
+  });
+
+  it('should allow pacing to be disabled with 0', () => {
+    expect(resolveBulkSendOptions({ templateId: 'tpl-1', pacingMs: 0 }).pacingMs).toBe(0);
+  });
+
+  it('should reject negative pacing', () => {
+    expect(() => resolveBulkSendOptions({ templateId: 'tpl-1', pacingMs: -1 })).toThrow(
+      ValidationError
+    );
+  });
+
+  it('should require a templateId', () => {
+    expect(() => resolveBulkSendOptions({} as never)).toThrow('templateId is required');
+  });
+});
+
+describe('validateRetryPolicy', () => {
+  it('should accept valid settings', () => {
+    expect(() => validateRetryPolicy({ timeoutMs: 30000, maxAttempts: 3 })).not.toThrow();
+    expect(() => validateRetryPolicy({})).not.toThrow();
+  });
+
+  it('should reject a string maxAttempts from a config file', () => {
+    expect(() => validateRetryPolicy({ maxAttempts: '3' as unknown as number })).toThrow(
+      ValidationError
+    );
+  });
+
+  it('should reject fractional maxAttempts', () => {
+    expect(() => validateRetryPolicy({ maxAttempts: 1.5 })).toThrow('must be an integer');
+  });
+
+  it('should reject a zero timeout', () => {
+    expect(() => validateRetryPolicy({ timeoutMs: 0 })).toThrow(ValidationError);
+  });
+});
@@ -0,0 +1,152 @@
+/**
+ * Redaction Tests
+ *
+ * Tests for the debug-logging redaction helpers and the opt-in
+ * debugLogging mode on HttpClient: dumps must never contain raw API keys,
+ * full email addresses, or file bytes.
+ */
+
+import { redactEmails, maskSecret, redactForLogging } from '../src/utils/redact';
+import { HttpClient } from '../src/http';
+
+describe('redaction helpers', () => {
+  describe('redactEmails', () => {
+    it('should keep the first character and domain', () => {
+      expect(redactEmails('signed by john.doe@example.com today')).toBe(
+        'signed by j***@example.com today'
+      );
+    });
+
+    it('should mask every email in the string', () => {
+      const masked = redactEmails('john@a.com, jane@b.org');
+      expect(masked).toBe('j***@a.com, j***@b.org');
+    });
+  });
+
+  describe('maskSecret', () => {
+    it('should keep the last four characters of long secrets', () => {
+      expect(maskSecret('sk-live-abcdef7f2a')).toBe('****7f2a');
+    });
+
+    it('should preserve the Bearer scheme', () => {
+      expect(maskSecret('Bearer sk-live-abcdef7f2a')).toBe('Bearer ****7f2a');
+    });
+
+    it('should fully mask short secrets', () => {
+      expect(maskSecret('abc')).toBe('****');
+    });
+  });
+
+  describe('redactForLogging', () => {
+    it('should mask sensitive keys and emails in nested objects', () => {
+      const redacted = redactForLogging({
+        Authorization: 'Bearer sk-live-abcdef7f2a',
+        recipients: [{ name: 'John Doe', email: 'john@example.com' }],
+        note: 'cc jane@example.com',
+      }) as Record<string, any>;
+
+      expect(redacted.Authorization).toBe('Bearer ****7f2a');
+      expect(redacted.recipients[0].email).toBe('j***@example.com');
+      expect(redacted.note).toBe('cc j***@example.com');
+    });
+
+    it('should replace buffers with their size', () => {
+      const redacted = redactForLogging({ file: Buffer.alloc(2048) }) as Record<string, unknown>;
+      expect(redacted.file).toBe('[2048 bytes redacted]');
+    });
+
+    it('should mask signature values', () => {
+      const redacted = redactForLogging({ signatureValue: 'data:image/png;base64,AAAA' }) as Record<string, unknown>;
+      expect(redacted.signatureValue).not.toContain('base64');
+    });
+  });
+});
+
+describe('HttpClient debugLogging', () => {
+  let mockFetch: jest.Mock;
+  let debugSpy: jest.SpyInstance;
+
+  const okResponse = {
+    ok: true,
+    status: 200,
+    headers: { get: () => 'application/json' },
+    json: async () => ({ data: { ok: true } }),
+  };
+
+  beforeEach(() => {
+    mockFetch = jest.fn().mockResolvedValue(okResponse);
+    global.fetch = mockFetch as unknown as typeof fetch;
+    debugSpy = jest.spyOn(console, 'debug').mockImplementation(() => undefined);
+  });
+
+  afterEach(() => {
+    debugSpy.mockRestore();
+  });
+
+  const makeClient = (debugLogging?: boolean) =>
+    new HttpClient({
+      apiKey: 'sk-live-secret-key-7f2a',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+      debugLogging,
+    });
+
+  it('should log requests and responses with secrets masked', async () => {
+    await makeClient(true).post('/turbosign/documents', {
+      recipients: [{ email: 'john@example.com' }],
+    });
+
+    const output = debugSpy.mock.calls.map((call) => call.join(' ')).join('\n');
+    expect(output).toContain('→ POST');
+    expect(output).toContain('← 200');
+    expect(output).not.toContain('sk-live-secret-key-7f2a');
+    expect(output).toContain('****7f2a');
+    expect(output).not.toContain('john@example.com');
+    expect(output).toContain('j***@example.com');
+  });
+
+  it('should log nothing by default', async () => {
+    await makeClient().get('/turbosign/documents');
+
+    expect(debugSpy).not.toHaveBeenCalled();
+  });
+});
+
+describe('HttpClient redacted debug view', () => {
+  const makeClient = () =>
+    new HttpClient({
+      apiKey: 'sk-live-secret-key-7f2a',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+  it('should mask the API key in JSON.stringify output', () => {
+    const serialized = JSON.stringify(makeClient());
+
+    expect(serialized).not.toContain('sk-live-secret-key-7f2a');
+    expect(serialized).toContain('Bearer ****7f2a');
+    expect(serialized).toContain('test-org-id');
+  });
+
+  it('should mask the API key in util.inspect output', () => {
+    // eslint-disable-next-line @typescript-eslint/no-var-requires
+    const util = require('util');
+    const inspected = util.inspect(makeClient());
+
+    expect(inspected).not.toContain('sk-live-secret-key-7f2a');
+    expect(inspected).toContain('Bearer ****7f2a');
+  });
+
+  it('should mask access tokens too', () => {
+    const client = new HttpClient({
+      accessToken: 'oauth-access-token-9b3c',
+      orgId: 'test-org-id',
+      senderEmail: 'support@company.com',
+    });
+
+    const view = client.toJSON();
+
+    expect(view.accessToken).toBe('Bearer ****9b3c');
+    expect(view.apiKey).toBeUndefined();
+  });
+});
@@ -0,0 +1,90 @@
+/**
+ * Runtime Helper Tests
+ *
+ * Tests for the runtime detection helpers that keep the SDK usable in
+ * browsers and edge workers: Node built-ins load lazily with a pointed
+ * error outside Node, and env reads tolerate a missing process.
+ */
+
+import {
+  isNode,
+  envVar,
+  requireFs,
+  requirePath,
+  randomUUID,
+  toBase64,
+  fromBase64,
+} from '../src/utils/runtime';
+import { ValidationError } from '../src/utils/errors';
+
+/** Make isNode() report false for the duration of fn, as in a worker */
+function outsideNode(fn: () => void): void {
+  const versions = process.versions;
+  Object.defineProperty(process, 'versions', { value: {}, configurable: true });
+  try {
+    fn();
+  } finally {
+    Object.defineProperty(process, 'versions', { value: versions, configurable: true });
+  }
+}
+
+describe('runtime helpers', () => {
+  describe('isNode', () => {
+    it('should be true under the Jest Node environment', () => {
+      expect(isNode()).toBe(true);
+    });
+
+    it('should be false when process.versions.node is absent', () => {
+      outsideNode(() => {
+        expect(isNode()).toBe(false);
+      });
+    });
+  });
+
+  describe('envVar', () => {
+    it('should read set variables', () => {
+      process.env.TURBODOCX_RUNTIME_TEST = 'value';
+      expect(envVar('TURBODOCX_RUNTIME_TEST')).toBe('value');
+      delete process.env.TURBODOCX_RUNTIME_TEST;
+    });
+
+    it('should return undefined for unset variables', () => {
+      expect(envVar('TURBODOCX_RUNTIME_TEST_UNSET')).toBeUndefined();
+    });
+  });
+
+  describe('lazy Node built-ins', () => {
+    it('should load fs and path under Node', () => {
+      expect(typeof requireFs().readFileSync).toBe('function');
+      expect(requirePath().basename('/tmp/contract.pdf')).toBe('contract.pdf');
+    });
+
+    it('should throw a ValidationError pointing at Buffer/File/fileLink outside Node', () => {
+      outsideNode(() => {
+        expect(() => requireFs()).toThrow(ValidationError);
+        expect(() => requireFs()).toThrow(/pass a Buffer, File, or fileLink/);
+      });
+    });
+  });
+
+  describe('randomUUID', () => {
+    it('should produce a v4 UUID', () => {
+      expect(randomUUID()).toMatch(
+        /^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/
+      );
+    });
+  });
+
+  describe('base64', () => {
+    it('should round-trip UTF-8 text', () => {
+      const text = '{"offset":42} — résumé ✓';
+      expect(fromBase64(toBase64(text))).toBe(text);
+    });
+
+    it('should match the Buffer encoding under Node', () => {
+      expect(toBase64('{"offset":10}')).toBe(
+        Buffer.from('{"offset":10}', 'utf8').toString('base64')
+      );
+    });
+  });
+});
@@ -0,0 +1,92 @@
+/**
+ * TemplateAnchor Tests
+ *
+ * Tests for anchor-based field positioning builders and regex safety checks
+ */
+
+import { TemplateAnchor } from '../src/utils/anchors';
+import { ValidationError } from '../src/utils/errors';
+
+describe('TemplateAnchor', () => {
+  describe('literal', () => {
+    it('should build a non-regex search anchor', () => {
+      const anchor = TemplateAnchor.literal('Sign here:');
+      expect(anchor.searchText).toBe('Sign here:');
+      expect(anchor.useRegex).toBe(false);
+    });
+
+    it('should reject empty text', () => {
+      expect(() => TemplateAnchor.literal('')).toThrow(ValidationError);
+    });
+  });
+
+  describe('regex', () => {
+    it('should build a regex search anchor for a valid pattern', () => {
+      const anchor = TemplateAnchor.regex('Invoice #\\d+');
+      expect(anchor.searchText).toBe('Invoice #\\d+');
+      expect(anchor.useRegex).toBe(true);
+    });
+
+    it('should reject patterns that do not compile', () => {
+      expect(() => TemplateAnchor.regex('([unclosed')).toThrow(ValidationError);
+    });
+
+    it('should reject nested unbounded quantifiers', () => {
+      expect(() => TemplateAnchor.regex('(a+)+')).toThrow(/catastrophic backtracking/);
+      expect(() => TemplateAnchor.regex('(\\w*)*suffix')).toThrow(ValidationError);
+    });
+
+    it('should reject oversized patterns', () => {
+      expect(() => TemplateAnchor.regex('a'.repeat(1001))).toThrow(ValidationError);
+    });
+  });
+
+  describe('tag', () => {
+    it('should build an anchor for a placeholder tag', () => {
+      expect(TemplateAnchor.tag('{SignatureTag}').anchor).toBe('{SignatureTag}');
+    });
+  });
+
+  describe('escape', () => {
+    it('should escape regex metacharacters', () => {
+      const escaped = TemplateAnchor.escape('Total: $100 (USD)');
+      expect(new RegExp(escaped).test('Total: $100 (USD)')).toBe(true);
+      expect(escaped).toBe('Total: \\$100 \\(USD\\)');
+    });
+  });
+
+  describe('chainable customization', () => {
+    it('should support chained placement, offset, and size', () => {
+      const anchor = TemplateAnchor.literal('Sign here:')
+        .withPlacement('below')
+        .withOffset(10, 5)
+        .withSize(200, 50);
+
+      expect(anchor.placement).toBe('below');
+      expect(anchor.offset).toEqual({ x: 10, y: 5 });
+      expect(anchor.size).toEqual({ width: 200, height: 50 });
+    });
+
+    it('should toggle case sensitivity', () => {
+      expect(TemplateAnchor.literal('sign').withCaseSensitive().caseSensitive).toBe(true);
+      expect(TemplateAnchor.literal('sign').caseInsensitive().caseSensitive).toBe(false);
+    });
+
+    it('should be usable directly as a field template', () => {
+      const template = TemplateAnchor.tag('{Sig}').withPlacement('replace').withSize(180, 40);
+      expect(JSON.parse(JSON.stringify(template))).toEqual({
+        anchor: '{Sig}',
+        placement: 'replace',
+        size: { width: 180, height: 40 },
+      });
+    });
+  });
+
+  it('should serialize to the template config shape', () => {
+    const anchor = TemplateAnchor.literal('Sign here:');
+    expect(JSON.parse(JSON.stringify(anchor))).toEqual({
+      searchText: 'Sign here:',
+      useRegex: false,
+    });
+  });
+});
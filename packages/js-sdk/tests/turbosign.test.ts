@@ -11,8 +11,10 @@
  * - getAuditTrail
  */
 
-import { TurboSign } from "../src/modules/sign";
+import { TurboSign, TurboSignClient } from "../src/modules/sign";
 import { HttpClient } from "../src/http";
+import { ConflictError, InvalidStateError, QuotaExceededError, QuotaLowError, RateLimitError } from "../src/utils/errors";
+import { Pacer } from "../src/utils/pacer";
 import type { Recipient, Field } from "../src/types/sign";
 
 // Mock the HttpClient
@@ -27,8 +29,11 @@ const MockedHttpClient = HttpClient as jest.MockedClass<typeof HttpClient>;
 describe("TurboSign Module", () => {
   beforeEach(() => {
     jest.clearAllMocks();
-    // Reset static client
+    // Reset static client, named profiles, and org-scoped handles
     (TurboSign as any).client = undefined;
+    (TurboSign as any).config = {};
+    (TurboSign as any).profiles = new Map();
+    (TurboSign as any).orgClients = new Map();
 
     // Mock getSenderConfig to return default values
     MockedHttpClient.prototype.getSenderConfig = jest.fn().mockReturnValue({
@@ -407,23 +412,890 @@ describe("TurboSign Module", () => {
     });
   });
 
+  describe("sendForAcknowledgment", () => {
+    const mockRecipients: Recipient[] = [
+      { name: "John Doe", email: "john@example.com", signingOrder: 1 },
+    ];
+
+    it("should send a document for acknowledgment without any fields", async () => {
+      const mockResponse = {
+        success: true,
+        documentId: "doc-ack",
+        status: "pending_acknowledgment",
+        recipients: [
+          { id: "r-1", name: "John Doe", email: "john@example.com", metadata: {} },
+        ],
+        message: "Document sent for acknowledgment",
+      };
+
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.sendForAcknowledgment({
+        fileLink: "https://storage.example.com/policy.pdf",
+        documentName: "Remote Work Policy 2026",
+        recipients: mockRecipients,
+      });
+
+      expect(result.documentId).toBe("doc-ack");
+      expect(result.status).toBe("pending_acknowledgment");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/single/send-for-acknowledgment",
+        expect.objectContaining({
+          recipients: JSON.stringify(mockRecipients),
+          documentName: "Remote Work Policy 2026",
+        })
+      );
+      // No signature fields are placed on acknowledgment documents
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(payload.fields).toBeUndefined();
+    });
+
+    it("should handle file upload for acknowledgment", async () => {
+      const mockResponse = {
+        success: true,
+        documentId: "doc-ack-upload",
+        status: "pending_acknowledgment",
+        message: "Document sent for acknowledgment",
+      };
+
+      MockedHttpClient.prototype.uploadFile = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.sendForAcknowledgment({
+        file: Buffer.from("mock-pdf-content"),
+        fileName: "policy.pdf",
+        recipients: mockRecipients,
+      });
+
+      expect(result.documentId).toBe("doc-ack-upload");
+      expect(MockedHttpClient.prototype.uploadFile).toHaveBeenCalledWith(
+        "/turbosign/single/send-for-acknowledgment",
+        expect.any(Buffer),
+        "file",
+        expect.any(Object)
+      );
+    });
+  });
+
+  describe("sendBulkFromCsv", () => {
+    const sendResponse = (documentId: string) => ({
+      success: true,
+      documentId,
+      status: "UNDER_REVIEW",
+      message: "Document sent for signing",
+    });
+    const bulkOptions = {
+      templateId: "template-1",
+      fieldLayoutId: "layout-1",
+      pacingMs: 0,
+    };
+
+    it("should send one envelope per row with interpolated document names", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValueOnce(sendResponse("doc-1"))
+        .mockResolvedValueOnce(sendResponse("doc-2"));
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const report = await TurboSign.sendBulkFromCsv(
+        "name,email\nJohn Doe,john@example.com\nJane Smith,jane@example.com",
+        { ...bulkOptions, documentName: "Offer letter - {{name}}" }
+      );
+
+      expect(report).toEqual({
+        total: 2,
+        sent: 2,
+        failed: 0,
+        results: [
+          { row: 1, email: "john@example.com", status: "sent", documentId: "doc-1" },
+          { row: 2, email: "jane@example.com", status: "sent", documentId: "doc-2" },
+        ],
+      });
+      const firstPayload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(firstPayload.documentName).toBe("Offer letter - John Doe");
+      expect(firstPayload.fieldLayoutId).toBe("layout-1");
+      expect(firstPayload.templateId).toBe("template-1");
+      expect(JSON.parse(firstPayload.recipients)).toEqual([
+        { name: "John Doe", email: "john@example.com", signingOrder: 1 },
+      ]);
+    });
+
+    it("should collect per-row validation errors without aborting the batch", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue(sendResponse("doc-1"));
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const report = await TurboSign.sendBulkFromCsv(
+        "name,email\nJohn Doe,not-an-email\nJane Smith,jane@example.com",
+        bulkOptions
+      );
+
+      expect(report.sent).toBe(1);
+      expect(report.failed).toBe(1);
+      expect(report.results[0].status).toBe("failed");
+      expect(report.results[0].error).toContain("not-an-email");
+      expect(report.results[1]).toEqual(
+        expect.objectContaining({ row: 2, status: "sent", documentId: "doc-1" })
+      );
+    });
+
+    it("should retry a row once after a rate-limit response", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockRejectedValueOnce(new RateLimitError())
+        .mockResolvedValueOnce(sendResponse("doc-1"));
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const report = await TurboSign.sendBulkFromCsv(
+        "name,email\nJohn Doe,john@example.com",
+        bulkOptions
+      );
+
+      expect(report.sent).toBe(1);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledTimes(2);
+    });
+
+    it("should turn extra columns into custom fields coerced by the org schema", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({
+        results: [
+          { key: "headcount", type: "number" },
+          { key: "remote", type: "boolean" },
+        ],
+      });
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue(sendResponse("doc-1"));
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const report = await TurboSign.sendBulkFromCsv(
+        "name,email,headcount,remote\nJohn Doe,john@example.com,12,true",
+        bulkOptions
+      );
+
+      expect(report.sent).toBe(1);
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(JSON.parse(payload.customFields)).toEqual({ headcount: 12, remote: true });
+    });
+
+    it("should reject a CSV without the required columns", async () => {
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await expect(
+        TurboSign.sendBulkFromCsv("name,team\nJohn Doe,Platform", bulkOptions)
+      ).rejects.toThrow("missing required column 'email'");
+    });
+
+    it("should pace rows through a provided Pacer instead of the fixed delay", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValueOnce(sendResponse("doc-1"))
+        .mockResolvedValueOnce(sendResponse("doc-2"))
+        .mockResolvedValueOnce(sendResponse("doc-3"));
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const pacer = new Pacer({ ratePerSecond: 1000, burst: 10 });
+      const acquire = jest.spyOn(pacer, "acquire");
+
+      const report = await TurboSign.sendBulkFromCsv(
+        "name,email\nA,a@example.com\nB,b@example.com\nC,c@example.com",
+        { ...bulkOptions, pacer }
+      );
+
+      expect(report.sent).toBe(3);
+      // One wait between consecutive rows, none after the last
+      expect(acquire).toHaveBeenCalledTimes(2);
+    });
+  });
+
+  describe("bulk jobs", () => {
+    const mockJob = {
+      id: "job-123",
+      status: "processing",
+      progress: { queued: 40, sent: 55, failed: 5 },
+      total: 100,
+      createdOn: "2026-08-30T12:00:00Z",
+    };
+
+    it("should get a bulk job with its progress counters", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue(mockJob);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const job = await TurboSign.getBulkJob("job-123");
+
+      expect(job.progress.sent).toBe(55);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/bulk-jobs/job-123"
+      );
+    });
+
+    it("should list bulk jobs with filters", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ results: [mockJob], totalRecords: 1 });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const list = await TurboSign.listBulkJobs({ status: "processing", limit: 10 });
+
+      expect(list.results).toHaveLength(1);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/bulk-jobs",
+        { status: "processing", limit: 10 }
+      );
+    });
+
+    it("should cancel a bulk job", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue({ ...mockJob, status: "cancelled" });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const job = await TurboSign.cancelBulkJob("job-123");
+
+      expect(job.status).toBe("cancelled");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/bulk-jobs/job-123/cancel"
+      );
+    });
+  });
+
+  describe("form mode", () => {
+    const mockRecipients: Recipient[] = [
+      { name: "John Doe", email: "john@example.com", signingOrder: 1 },
+    ];
+    const textField: Field = {
+      type: "text",
+      page: 1,
+      x: 100,
+      y: 500,
+      width: 150,
+      height: 30,
+      recipientEmail: "john@example.com",
+      required: true,
+    };
+
+    it("should send form-mode documents without signature fields", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-form",
+        status: "UNDER_REVIEW",
+        message: "Document sent",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.sendSignature({
+        fileLink: "https://storage.example.com/survey.pdf",
+        recipients: mockRecipients,
+        fields: [textField, { ...textField, type: "checkbox", y: 560 }],
+        mode: "form",
+      });
+
+      expect(result.documentId).toBe("doc-form");
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(payload.mode).toBe("form");
+    });
+
+    it("should reject signature fields in form mode", async () => {
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await expect(
+        TurboSign.sendSignature({
+          fileLink: "https://storage.example.com/survey.pdf",
+          recipients: mockRecipients,
+          fields: [{ ...textField, type: "signature" }],
+          mode: "form",
+        })
+      ).rejects.toThrow("Form mode documents cannot contain 'signature' fields");
+    });
+
+    it("should return submitted data from getFieldValues", async () => {
+      const mockResponse = {
+        documentId: "doc-form",
+        complete: true,
+        results: [
+          {
+            fieldId: "f-1",
+            type: "text",
+            recipientEmail: "john@example.com",
+            label: "Department",
+            value: "Engineering",
+            submittedOn: "2026-08-30T12:00:00Z",
+          },
+          {
+            fieldId: "f-2",
+            type: "checkbox",
+            recipientEmail: "john@example.com",
+            value: true,
+          },
+        ],
+      };
+
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const values = await TurboSign.getFieldValues("doc-form");
+
+      expect(values.complete).toBe(true);
+      expect(values.results).toHaveLength(2);
+      expect(values.results[0].value).toBe("Engineering");
+      expect(values.results[1].value).toBe(true);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-form/field-values"
+      );
+    });
+  });
+
+  describe("saveFieldLayout", () => {
+    it("should save a field layout against a template", async () => {
+      const mockResponse = {
+        layoutId: "layout-123",
+        templateId: "template-456",
+        fieldCount: 1,
+        message: "Field layout saved",
+      };
+
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const fields = [
+        {
+          type: "signature" as const,
+          page: 1,
+          x: 100,
+          y: 500,
+          width: 200,
+          height: 50,
+          recipientEmail: "john@example.com",
+        },
+      ];
+      const result = await TurboSign.saveFieldLayout("template-456", fields);
+
+      expect(result.layoutId).toBe("layout-123");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/templates/template-456/field-layouts",
+        { fields }
+      );
+    });
+
+    it("should allow sending with a fieldLayoutId instead of fields", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-123",
+        status: "sent",
+        message: "sent",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await TurboSign.sendSignature({
+        templateId: "template-456",
+        fieldLayoutId: "layout-123",
+        recipients: [
+          { name: "John Doe", email: "john@example.com", signingOrder: 1 },
+        ],
+      });
+
+      const [path, body] = (MockedHttpClient.prototype.post as jest.Mock).mock
+        .calls[0];
+      expect(path).toBe("/turbosign/single/prepare-for-signing");
+      expect(body.fieldLayoutId).toBe("layout-123");
+      expect(body.fields).toBeUndefined();
+    });
+  });
+
   describe("getStatus", () => {
     it("should get document status", async () => {
       // HTTP client auto-unwraps {data: ...} responses
       const mockResponse = {
-        status: "under_review",
+        status: "under_review",
+      };
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.getStatus("doc-123");
+
+      expect(result.status).toBe("under_review");
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/status"
+      );
+    });
+  });
+
+  describe("getStatuses", () => {
+    it("should poll many documents concurrently and return a map", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockImplementation((path: string) =>
+          Promise.resolve({ status: path.includes("doc-1") ? "completed" : "sent" })
+        );
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const statuses = await TurboSign.getStatuses(["doc-1", "doc-2", "doc-3"]);
+
+      expect(statuses["doc-1"].status).toBe("completed");
+      expect(statuses["doc-2"].status).toBe("sent");
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledTimes(3);
+    });
+
+    it("should return an empty map for no IDs", async () => {
+      MockedHttpClient.prototype.get = jest.fn();
+      TurboSign.configure({ apiKey: "test-key" });
+
+      expect(await TurboSign.getStatuses([])).toEqual({});
+      expect(MockedHttpClient.prototype.get).not.toHaveBeenCalled();
+    });
+  });
+
+  describe("watch", () => {
+    it("should emit initial statuses and changes, completing at terminal states", async () => {
+      const polls: Record<string, number> = {};
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockImplementation((path: string) => {
+          const documentId = path.split("/")[3];
+          polls[documentId] = (polls[documentId] || 0) + 1;
+          return Promise.resolve({
+            status: polls[documentId] === 1 ? "sent" : "completed",
+          });
+        });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const changes = [];
+      for await (const change of TurboSign.watch(["doc-1", "doc-2"], {
+        pollIntervalMs: 1,
+        maxPollIntervalMs: 2,
+      })) {
+        changes.push(change);
+      }
+
+      expect(changes).toHaveLength(4);
+      const doc1 = changes.filter((c) => c.documentId === "doc-1");
+      expect(doc1.map((c) => c.status)).toEqual(["sent", "completed"]);
+      expect(doc1[0].previousStatus).toBeUndefined();
+      expect(doc1[1].previousStatus).toBe("sent");
+    });
+
+    it("should not emit when a poll returns an unchanged status", async () => {
+      let calls = 0;
+      MockedHttpClient.prototype.get = jest.fn().mockImplementation(() => {
+        calls += 1;
+        return Promise.resolve({ status: calls <= 3 ? "sent" : "completed" });
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const statuses = [];
+      for await (const change of TurboSign.watch(["doc-1"], {
+        pollIntervalMs: 1,
+        maxPollIntervalMs: 1,
+      })) {
+        statuses.push(change.status);
+      }
+
+      // Quiet polls between the two transitions produce no emissions
+      expect(statuses).toEqual(["sent", "completed"]);
+      expect(calls).toBeGreaterThanOrEqual(4);
+    });
+
+    it("should complete after one emission for already-terminal documents", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "voided" });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const changes = [];
+      for await (const change of TurboSign.watch(["doc-1"])) {
+        changes.push(change);
+      }
+
+      expect(changes).toEqual([{ documentId: "doc-1", status: "voided" }]);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe("estimateCost", () => {
+    it("should post the batch spec and return the estimate", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        envelopeCount: 500,
+        estimatedPageCount: 2000,
+        estimatedCredits: 500,
+        creditsRemaining: 750,
+        withinPlanLimits: true,
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const estimate = await TurboSign.estimateCost({
+        envelopeCount: 500,
+        pagesPerDocument: 4,
+      });
+
+      expect(estimate.withinPlanLimits).toBe(true);
+      expect(estimate.estimatedCredits).toBe(500);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/estimate",
+        { envelopeCount: 500, pagesPerDocument: 4 }
+      );
+    });
+  });
+
+  describe("time zone handling", () => {
+    const mockRecipients: Recipient[] = [
+      { name: "John Doe", email: "john@example.com", signingOrder: 1 },
+    ];
+    const mockFields: Field[] = [
+      {
+        type: "date",
+        page: 1,
+        x: 100,
+        y: 500,
+        width: 200,
+        height: 50,
+        recipientEmail: "john@example.com",
+      },
+    ];
+
+    beforeEach(() => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-tz",
+        status: "UNDER_REVIEW",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+    });
+
+    it("should pass a valid request-level timeZone through to the payload", async () => {
+      await TurboSign.sendSignature({
+        fileLink: "https://storage.example.com/contract.pdf",
+        recipients: mockRecipients,
+        fields: mockFields,
+        timeZone: "America/New_York",
+      });
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/single/prepare-for-signing",
+        expect.objectContaining({ timeZone: "America/New_York" })
+      );
+    });
+
+    it("should serialize per-recipient timeZone with the recipients", async () => {
+      await TurboSign.sendSignature({
+        fileLink: "https://storage.example.com/contract.pdf",
+        recipients: [{ ...mockRecipients[0], timeZone: "Europe/Berlin" }],
+        fields: mockFields,
+      });
+
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(JSON.parse(payload.recipients)[0].timeZone).toBe("Europe/Berlin");
+    });
+
+    it("should reject an unknown request-level timeZone before uploading", async () => {
+      await expect(
+        TurboSign.sendSignature({
+          fileLink: "https://storage.example.com/contract.pdf",
+          recipients: mockRecipients,
+          fields: mockFields,
+          timeZone: "America/Not_A_Zone",
+        })
+      ).rejects.toThrow("not a known IANA time zone");
+
+      expect(MockedHttpClient.prototype.post).not.toHaveBeenCalled();
+    });
+
+    it("should reject an unknown per-recipient timeZone naming the recipient", async () => {
+      await expect(
+        TurboSign.sendSignature({
+          fileLink: "https://storage.example.com/contract.pdf",
+          recipients: [{ ...mockRecipients[0], timeZone: "Mars/Olympus_Mons" }],
+          fields: mockFields,
+        })
+      ).rejects.toThrow("timeZone for recipient john@example.com");
+    });
+  });
+
+  describe("accessibility options", () => {
+    beforeEach(() => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-a11y",
+        status: "UNDER_REVIEW",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+    });
+
+    it("should serialize accessibility options into the payload", async () => {
+      await TurboSign.sendSignature({
+        fileLink: "https://storage.example.com/contract.pdf",
+        recipients: [{ name: "John Doe", email: "john@example.com", signingOrder: 1 }],
+        fields: [
+          { type: "signature", page: 1, x: 100, y: 500, width: 200, height: 50, recipientEmail: "john@example.com" },
+        ],
+        accessibility: { largeTextMode: true, screenReaderHints: true },
+      });
+
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(JSON.parse(payload.accessibility)).toEqual({ largeTextMode: true, screenReaderHints: true });
+    });
+
+    it("should pass per-field ariaLabel through with the fields", async () => {
+      await TurboSign.sendSignature({
+        fileLink: "https://storage.example.com/contract.pdf",
+        recipients: [{ name: "John Doe", email: "john@example.com", signingOrder: 1 }],
+        fields: [
+          {
+            type: "signature",
+            page: 1,
+            x: 100,
+            y: 500,
+            width: 200,
+            height: 50,
+            recipientEmail: "john@example.com",
+            ariaLabel: "Sign here to accept the contract terms",
+          },
+        ],
+      });
+
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(JSON.parse(payload.fields)[0].ariaLabel).toBe("Sign here to accept the contract terms");
+    });
+  });
+
+  describe("generation lineage", () => {
+    it("should surface generatedFrom metadata on status responses", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({
+        status: "completed",
+        generatedFrom: { templateId: "tmpl-1", deliverableId: "del-1" },
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const status = await TurboSign.getStatus("doc-1");
+
+      expect(status.generatedFrom?.deliverableId).toBe("del-1");
+      expect(status.generatedFrom?.templateId).toBe("tmpl-1");
+    });
+  });
+
+  describe("getStatusWithResponse", () => {
+    it("should return the typed data with HTTP metadata", async () => {
+      MockedHttpClient.prototype.requestWithResponse = jest.fn().mockResolvedValue({
+        data: { status: "completed" },
+        status: 200,
+        headers: { "x-ratelimit-remaining": "42" },
+        requestId: "req-1",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const response = await TurboSign.getStatusWithResponse("doc-1");
+
+      expect(response.data.status).toBe("completed");
+      expect(response.headers["x-ratelimit-remaining"]).toBe("42");
+      expect(MockedHttpClient.prototype.requestWithResponse).toHaveBeenCalledWith(
+        "GET",
+        "/turbosign/documents/doc-1/status"
+      );
+    });
+  });
+
+  describe("custom fields", () => {
+    const sendRequest = (customFields: Record<string, string | number | boolean>) => ({
+      fileLink: "https://storage.example.com/contract.pdf",
+      recipients: [{ name: "John Doe", email: "john@example.com", signingOrder: 1 }],
+      fields: [
+        { type: "signature" as const, page: 1, x: 100, y: 500, width: 200, height: 50, recipientEmail: "john@example.com" },
+      ],
+      customFields,
+    });
+
+    beforeEach(() => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({
+        results: [
+          { key: "costCenter", type: "string", required: false },
+          { key: "contractType", type: "enum", allowedValues: ["nda", "msa"] },
+        ],
+      });
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-cf",
+        status: "UNDER_REVIEW",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+    });
+
+    it("should fetch the org schema", async () => {
+      const { results } = await TurboSign.getCustomFieldDefinitions();
+
+      expect(results).toHaveLength(2);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith("/turbosign/custom-field-definitions");
+    });
+
+    it("should validate and serialize customFields on send", async () => {
+      await TurboSign.sendSignature(sendRequest({ costCenter: "CC-1", contractType: "msa" }));
+
+      const payload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(JSON.parse(payload.customFields)).toEqual({ costCenter: "CC-1", contractType: "msa" });
+    });
+
+    it("should reject values that fail the schema before uploading", async () => {
+      await expect(TurboSign.sendSignature(sendRequest({ contractType: "lease" }))).rejects.toThrow(
+        "must be one of: nda, msa"
+      );
+
+      expect(MockedHttpClient.prototype.post).not.toHaveBeenCalled();
+    });
+
+    it("should fetch the schema once and reuse it across sends", async () => {
+      await TurboSign.sendSignature(sendRequest({ costCenter: "CC-1" }));
+      await TurboSign.sendSignature(sendRequest({ costCenter: "CC-2" }));
+
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledTimes(1);
+    });
+
+    it("should not fetch the schema when no customFields are sent", async () => {
+      const request = sendRequest({}) as Record<string, unknown>;
+      delete request.customFields;
+
+      await TurboSign.sendSignature(request as any);
+
+      expect(MockedHttpClient.prototype.get).not.toHaveBeenCalled();
+    });
+  });
+
+  describe("quota guard", () => {
+    const mockEstimate = (estimatedCredits: number, creditsRemaining: number, withinPlanLimits = true) => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        envelopeCount: estimatedCredits,
+        estimatedCredits,
+        creditsRemaining,
+        withinPlanLimits,
+      });
+    };
+
+    it("should report ok when the batch fits comfortably", async () => {
+      mockEstimate(100, 750);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const check = await TurboSign.checkQuota({ envelopeCount: 100 });
+
+      expect(check).toEqual({
+        status: "ok",
+        estimatedCredits: 100,
+        creditsRemaining: 750,
+        creditsAfter: 650,
+      });
+    });
+
+    it("should report low when the batch would drop below the low-water mark", async () => {
+      mockEstimate(100, 150);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const check = await TurboSign.checkQuota({ envelopeCount: 100 }, 100);
+
+      expect(check.status).toBe("low");
+      expect(check.creditsAfter).toBe(50);
+    });
+
+    it("should report exceeded when the batch needs more credits than remain", async () => {
+      mockEstimate(500, 100);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const check = await TurboSign.checkQuota({ envelopeCount: 500 });
+
+      expect(check.status).toBe("exceeded");
+    });
+
+    it("should report exceeded when the plan rejects the batch", async () => {
+      mockEstimate(100, 750, false);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const check = await TurboSign.checkQuota({ envelopeCount: 100 });
+
+      expect(check.status).toBe("exceeded");
+    });
+
+    it("should throw QuotaExceededError from assertQuota with the credit numbers", async () => {
+      mockEstimate(500, 100);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const error = await TurboSign.assertQuota({ envelopeCount: 500 }).catch((e) => e);
+
+      expect(error).toBeInstanceOf(QuotaExceededError);
+      expect(error.code).toBe("QUOTA_EXCEEDED");
+      expect(error.estimatedCredits).toBe(500);
+      expect(error.creditsRemaining).toBe(100);
+    });
+
+    it("should throw QuotaLowError from assertQuota below the low-water mark", async () => {
+      mockEstimate(100, 150);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await expect(TurboSign.assertQuota({ envelopeCount: 100 }, 100)).rejects.toThrow(QuotaLowError);
+    });
+
+    it("should resolve with the check when the batch fits", async () => {
+      mockEstimate(100, 750);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const check = await TurboSign.assertQuota({ envelopeCount: 100 });
+
+      expect(check.status).toBe("ok");
+    });
+  });
+
+  describe("simulateRecipientAction", () => {
+    it("should post the recipient action to the sandbox endpoint", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        documentId: "doc-1",
+        recipientId: "rec-1",
+        action: "sign",
+        status: "completed",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.simulateRecipientAction("doc-1", "rec-1", "sign");
+
+      expect(result.status).toBe("completed");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/sandbox/documents/doc-1/simulate",
+        { recipientId: "rec-1", action: "sign" }
+      );
+    });
+  });
+
+  describe("sender verification", () => {
+    it("should list verified senders", async () => {
+      const mockResponse = {
+        results: [
+          { email: "support@company.com", status: "verified", verifiedAt: "2025-01-01T00:00:00Z" },
+          { email: "legal@company.com", status: "pending" },
+        ],
       };
 
-      MockedHttpClient.prototype.get = jest
-        .fn()
-        .mockResolvedValue(mockResponse);
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue(mockResponse);
       TurboSign.configure({ apiKey: "test-key" });
 
-      const result = await TurboSign.getStatus("doc-123");
+      const result = await TurboSign.listVerifiedSenders();
 
-      expect(result.status).toBe("under_review");
+      expect(result.results).toHaveLength(2);
       expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
-        "/turbosign/documents/doc-123/status"
+        "/turbosign/verified-senders"
+      );
+    });
+
+    it("should request verification for a sender address", async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue({ email: "support@company.com", status: "pending" });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.verifySender("support@company.com");
+
+      expect(result.status).toBe("pending");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/verified-senders/verify",
+        { email: "support@company.com" }
       );
     });
   });
@@ -451,10 +1323,42 @@ describe("TurboSign Module", () => {
 
       expect(result).toBeInstanceOf(Blob);
       expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
-        "/turbosign/documents/doc-123/download"
+        "/turbosign/documents/doc-123/download",
+        undefined,
+        undefined
       );
       expect(mockFetch).toHaveBeenCalledWith(
-        "https://s3.example.com/presigned-url"
+        "https://s3.example.com/presigned-url",
+        { signal: undefined }
+      );
+    });
+
+    it("should forward the caller's signal to the presigned-URL fetch", async () => {
+      const mockPresignedResponse = {
+        downloadUrl: "https://s3.example.com/presigned-url",
+        fileName: "signed-document.pdf",
+      };
+
+      const mockFetchResponse = {
+        ok: true,
+        headers: { get: () => null },
+        arrayBuffer: jest.fn().mockResolvedValue(new ArrayBuffer(4)),
+      };
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue(mockPresignedResponse);
+      mockFetch.mockResolvedValue(mockFetchResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const controller = new AbortController();
+      await TurboSign.download("doc-123", { signal: controller.signal });
+
+      // Aborting must cancel the S3 transfer itself, not just the
+      // presigned-URL lookup
+      expect(mockFetch).toHaveBeenCalledWith(
+        "https://s3.example.com/presigned-url",
+        { signal: controller.signal }
       );
     });
 
@@ -492,6 +1396,7 @@ describe("TurboSign Module", () => {
         voidedAt: "2026-01-26T12:00:00.000Z",
       };
 
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({ status: "sent" });
       MockedHttpClient.prototype.post = jest
         .fn()
         .mockResolvedValue(mockResponse);
@@ -509,6 +1414,222 @@ describe("TurboSign Module", () => {
         { reason: "Document needs revision" }
       );
     });
+
+    it("should send the expectedVersion precondition", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({ status: "sent" });
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        id: "doc-123",
+        name: "Test Document",
+        status: "voided",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await TurboSign.void("doc-123", "Stale draft", { expectedVersion: 4 });
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/void",
+        { reason: "Stale draft", expectedVersion: 4 }
+      );
+    });
+
+    it("should surface a version mismatch as a conflict", async () => {
+      const conflict = new ConflictError("Document is at version 5, expected 4");
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({ status: "sent" });
+      MockedHttpClient.prototype.post = jest.fn().mockRejectedValue(conflict);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await expect(
+        TurboSign.void("doc-123", "Stale draft", { expectedVersion: 4 })
+      ).rejects.toBeInstanceOf(ConflictError);
+    });
+
+    it("should refuse to void a completed document", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({ status: "completed" });
+      MockedHttpClient.prototype.post = jest.fn();
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const error = await TurboSign.void("doc-123", "Too late").catch((e) => e);
+
+      expect(error).toBeInstanceOf(InvalidStateError);
+      expect(error.message).toContain("completed");
+      expect(error.currentStatus).toBe("completed");
+      expect(MockedHttpClient.prototype.post).not.toHaveBeenCalled();
+    });
+
+    it("should void a terminal document when force is set", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockResolvedValue({ status: "completed" });
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        id: "doc-123",
+        status: "voided",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.void("doc-123", "Admin override", { force: true });
+
+      expect(result.status).toBe("voided");
+      expect(MockedHttpClient.prototype.get).not.toHaveBeenCalled();
+    });
+
+    it("should still void when the status pre-check fails", async () => {
+      MockedHttpClient.prototype.get = jest.fn().mockRejectedValue(new Error("status down"));
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        id: "doc-123",
+        status: "voided",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.void("doc-123", "Best effort");
+
+      expect(result.status).toBe("voided");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/void",
+        { reason: "Best effort" }
+      );
+    });
+  });
+
+  describe("listDocuments", () => {
+    it("should list documents with archived filter", async () => {
+      const mockResponse = {
+        results: [
+          { id: "doc-123", name: "Old Contract", status: "completed", archived: true },
+        ],
+        totalRecords: 1,
+      };
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.listDocuments({ archived: true, limit: 10 });
+
+      expect(result.totalRecords).toBe(1);
+      expect(result.results[0].archived).toBe(true);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/documents",
+        { archived: true, limit: 10 }
+      );
+    });
+  });
+
+  describe("listExpiring", () => {
+    it("should list documents expiring within the window", async () => {
+      const mockResponse = {
+        results: [
+          {
+            id: "doc-123",
+            name: "Renewal Contract",
+            status: "sent",
+            expiresOn: "2026-02-01T00:00:00.000Z",
+            daysRemaining: 3,
+          },
+        ],
+        totalRecords: 1,
+      };
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.listExpiring(7);
+
+      expect(result.results[0].daysRemaining).toBe(3);
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledWith(
+        "/turbosign/documents/expiring",
+        { withinDays: 7 }
+      );
+    });
+  });
+
+  describe("archiveDocument / unarchiveDocument", () => {
+    it("should archive a document", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        id: "doc-123",
+        name: "Test Document",
+        status: "completed",
+        archived: true,
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.archiveDocument("doc-123");
+
+      expect(result.archived).toBe(true);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/archive"
+      );
+    });
+
+    it("should unarchive a document", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        id: "doc-123",
+        name: "Test Document",
+        status: "completed",
+        archived: false,
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.unarchiveDocument("doc-123");
+
+      expect(result.archived).toBe(false);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/unarchive"
+      );
+    });
+  });
+
+  describe("cloneDocument", () => {
+    it("should clone a document with new recipients", async () => {
+      // HTTP client auto-unwraps {data: ...} responses
+      const mockResponse = {
+        success: true,
+        documentId: "doc-456",
+        sourceDocumentId: "doc-123",
+        status: "draft",
+        message: "Document cloned successfully",
+      };
+
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValue(mockResponse);
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.cloneDocument("doc-123", {
+        newRecipients: [
+          { name: "Jane Smith", email: "jane@example.com", signingOrder: 1 },
+        ],
+      });
+
+      expect(result.documentId).toBe("doc-456");
+      expect(result.sourceDocumentId).toBe("doc-123");
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/clone",
+        {
+          newRecipients: [
+            { name: "Jane Smith", email: "jane@example.com", signingOrder: 1 },
+          ],
+        }
+      );
+    });
+
+    it("should clone a document without overrides", async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue({
+        success: true,
+        documentId: "doc-456",
+        sourceDocumentId: "doc-123",
+        status: "draft",
+        message: "Document cloned successfully",
+      });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await TurboSign.cloneDocument("doc-123");
+
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledWith(
+        "/turbosign/documents/doc-123/clone",
+        {}
+      );
+    });
   });
 
   describe("resend", () => {
@@ -705,4 +1826,149 @@ describe("TurboSign Module", () => {
       ).rejects.toEqual(rateLimitError);
     });
   });
+
+  describe("instance clients", () => {
+    it("should let two clients with different orgs coexist", async () => {
+      const usClient = new TurboSignClient({ apiKey: "key", orgId: "org-us" });
+      const euClient = new TurboSignClient({ apiKey: "key", orgId: "org-eu" });
+
+      expect(MockedHttpClient).toHaveBeenCalledWith(
+        expect.objectContaining({ orgId: "org-us" })
+      );
+      expect(MockedHttpClient).toHaveBeenCalledWith(
+        expect.objectContaining({ orgId: "org-eu" })
+      );
+
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "completed" });
+
+      await usClient.getStatus("doc-1");
+      await euClient.getStatus("doc-2");
+
+      expect(MockedHttpClient.prototype.get).toHaveBeenCalledTimes(2);
+    });
+
+    it("should construct a single HttpClient across many calls", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "sent" });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      await TurboSign.getStatus("doc-1");
+      await TurboSign.getStatus("doc-2");
+      await TurboSign.getAuditTrail("doc-1");
+
+      // Connection pool (and TLS session) reuse depends on this being 1
+      expect(MockedHttpClient).toHaveBeenCalledTimes(1);
+    });
+
+    it("should route static facade calls through the shared instance", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "sent" });
+      TurboSign.configure({ apiKey: "test-key" });
+
+      const result = await TurboSign.getStatus("doc-1");
+
+      expect(result.status).toBe("sent");
+      expect((TurboSign as any).client).toBeInstanceOf(TurboSignClient);
+    });
+  });
+
+  describe("configuration profiles", () => {
+    it("should route calls through a named profile's client", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "completed" });
+
+      TurboSign.configureProfile("eu", {
+        apiKey: "eu-key",
+        orgId: "org-eu",
+        senderEmail: "eu@company.com",
+        baseUrl: "https://api-eu.turbodocx.com",
+      });
+
+      const result = await TurboSign.withProfile("eu").getStatus("doc-1");
+
+      expect(result.status).toBe("completed");
+      expect(MockedHttpClient).toHaveBeenCalledWith(
+        expect.objectContaining({ orgId: "org-eu" })
+      );
+    });
+
+    it("should throw ValidationError for an unknown profile", () => {
+      expect(() => TurboSign.withProfile("apac")).toThrow(
+        "No profile named 'apac'"
+      );
+    });
+
+    it("should keep profiles independent of the default client", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "sent" });
+
+      TurboSign.configure({ apiKey: "us-key", orgId: "org-us" });
+      TurboSign.configureProfile("eu", { apiKey: "eu-key", orgId: "org-eu" });
+
+      await TurboSign.getStatus("doc-us");
+      await TurboSign.withProfile("eu").getStatus("doc-eu");
+
+      // One client per credential set, not per call
+      expect(MockedHttpClient).toHaveBeenCalledTimes(2);
+      expect(MockedHttpClient).toHaveBeenCalledWith(
+        expect.objectContaining({ orgId: "org-us" })
+      );
+      expect(MockedHttpClient).toHaveBeenCalledWith(
+        expect.objectContaining({ orgId: "org-eu" })
+      );
+    });
+  });
+
+  describe("forOrg", () => {
+    it("should reuse the default credentials with only the org swapped", async () => {
+      MockedHttpClient.prototype.get = jest
+        .fn()
+        .mockResolvedValue({ status: "sent" });
+
+      TurboSign.configure({
+        apiKey: "test-key",
+        orgId: "org-default",
+        senderEmail: "support@company.com",
+      });
+
+      const result = await TurboSign.forOrg("org-tenant-1").getStatus("doc-1");
+
+      expect(result.status).toBe("sent");
+      expect(MockedHttpClient).toHaveBeenCalledWith({
+        apiKey: "test-key",
+        orgId: "org-tenant-1",
+        senderEmail: "support@company.com",
+      });
+    });
+
+    it("should cache one handle per org", () => {
+      TurboSign.configure({ apiKey: "test-key", orgId: "org-default" });
+
+      const first = TurboSign.forOrg("org-tenant-1");
+      const second = TurboSign.forOrg("org-tenant-1");
+      const other = TurboSign.forOrg("org-tenant-2");
+
+      expect(second).toBe(first);
+      expect(other).not.toBe(first);
+    });
+
+    it("should drop cached handles when configure is called again", () => {
+      TurboSign.configure({ apiKey: "old-key", orgId: "org-default" });
+      const stale = TurboSign.forOrg("org-tenant-1");
+
+      TurboSign.configure({ apiKey: "new-key", orgId: "org-default" });
+      const fresh = TurboSign.forOrg("org-tenant-1");
+
+      expect(fresh).not.toBe(stale);
+      expect(MockedHttpClient).toHaveBeenLastCalledWith(
+        expect.objectContaining({ apiKey: "new-key", orgId: "org-tenant-1" })
+      );
+    });
+  });
 });
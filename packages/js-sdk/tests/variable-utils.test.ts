@@ -0,0 +1,267 @@
+/**
+ * Variable Builder Utility Tests
+ */
+
+import {
+  dateVariable,
+  formattedVariable,
+  FormatHints,
+  loopVariable,
+  loopGroupedBy,
+  Expr,
+  conditionalVariable,
+  VariableSet,
+  subtemplateVariable,
+  autoVariable,
+} from '../src/utils/variables';
+import { ValidationError } from '../src/utils/errors';
+
+describe('dateVariable', () => {
+  const date = new Date('2026-08-31T12:00:00Z');
+
+  it('should format with the default locale and medium style', () => {
+    const variable = dateVariable('{SignedDate}', date);
+
+    expect(variable.placeholder).toBe('{SignedDate}');
+    expect(variable.mimeType).toBe('text');
+    expect(variable.text).toBe(
+      new Intl.DateTimeFormat('en-US', { dateStyle: 'medium' }).format(date)
+    );
+  });
+
+  it('should localize for the requested locale', () => {
+    const variable = dateVariable('{SignedDate}', date, {
+      locale: 'de-DE',
+      dateStyle: 'long',
+    });
+
+    expect(variable.text).toBe(
+      new Intl.DateTimeFormat('de-DE', { dateStyle: 'long' }).format(date)
+    );
+  });
+
+  it('should accept an ISO date string', () => {
+    const variable = dateVariable('{SignedDate}', '2026-08-31');
+
+    expect(variable.text).toBe(
+      new Intl.DateTimeFormat('en-US', { dateStyle: 'medium' }).format(new Date('2026-08-31'))
+    );
+  });
+
+  it('should emit the raw ISO date when requested', () => {
+    const variable = dateVariable('{SignedDate}', date, { raw: true });
+
+    expect(variable.text).toBe('2026-08-31');
+  });
+
+  it('should throw ValidationError for an unparseable date', () => {
+    expect(() => dateVariable('{SignedDate}', 'not-a-date')).toThrow(ValidationError);
+  });
+
+  it('should throw ValidationError for an invalid locale tag', () => {
+    expect(() => dateVariable('{SignedDate}', date, { locale: 'no pe' })).toThrow(
+      ValidationError
+    );
+  });
+});
+
+describe('formattedVariable', () => {
+  it('should attach a currency hint to a raw amount', () => {
+    const variable = formattedVariable('{Total}', 1249.5, FormatHints.currency('usd'));
+
+    expect(variable).toEqual({
+      placeholder: '{Total}',
+      text: '1249.5',
+      mimeType: 'text',
+      format: 'currency:USD',
+    });
+  });
+
+  it('should attach date and number hints', () => {
+    expect(FormatHints.date('longDate')).toBe('date:longDate');
+    expect(FormatHints.number('#,##0.00')).toBe('number:#,##0.00');
+    expect(FormatHints.percent()).toBe('percent');
+  });
+
+  it('should reject a malformed currency code', () => {
+    expect(() => FormatHints.currency('usdollars')).toThrow(ValidationError);
+  });
+});
+
+describe('loopVariable', () => {
+  const rows = [
+    { desc: 'Design', amount: 1200 },
+    { desc: 'Build', amount: 4800 },
+  ];
+
+  it('should render each row into a variableStack entry with metadata', () => {
+    const [loop] = loopVariable(
+      '{LineItems}',
+      rows,
+      (row, meta) => `${meta.index}${meta.first ? '*' : ''}${meta.last ? '$' : ''} ${row.desc}`
+    );
+
+    expect(loop.placeholder).toBe('{LineItems}');
+    expect(loop.variableStack).toEqual([
+      { text: '0* Design', mimeType: 'text' },
+      { text: '1$ Build', mimeType: 'text' },
+    ]);
+  });
+
+  it('should compute count and sum aggregates client-side', () => {
+    const variables = loopVariable('{LineItems}', rows, (row) => String(row.desc), {
+      sum: ['amount'],
+    });
+
+    expect(variables).toHaveLength(3);
+    expect(variables[1]).toEqual({
+      placeholder: '{LineItemsCount}',
+      text: '2',
+      mimeType: 'text',
+    });
+    expect(variables[2]).toEqual({
+      placeholder: '{LineItemsAmountSum}',
+      text: '6000',
+      mimeType: 'text',
+    });
+  });
+
+  it('should throw ValidationError when summing a non-numeric key', () => {
+    expect(() =>
+      loopVariable('{LineItems}', rows, (row) => String(row.desc), { sum: ['desc'] })
+    ).toThrow(ValidationError);
+  });
+});
+
+describe('loopGroupedBy', () => {
+  it('should group rows preserving first-seen order', () => {
+    const groups = loopGroupedBy(
+      [
+        { category: 'hardware', item: 'Server' },
+        { category: 'services', item: 'Setup' },
+        { category: 'hardware', item: 'Switch' },
+      ],
+      'category'
+    );
+
+    expect(groups.map((g) => g.key)).toEqual(['hardware', 'services']);
+    expect(groups[0].rows).toHaveLength(2);
+    expect(groups[1].rows).toEqual([{ category: 'services', item: 'Setup' }]);
+  });
+});
+
+describe('Expr', () => {
+  it('should serialize comparisons with quoted string literals', () => {
+    expect(Expr.gt('total', 1000).toString()).toBe('total > 1000');
+    expect(Expr.eq('region', 'EU').toString()).toBe("region == 'EU'");
+    expect(Expr.ne('active', true).toString()).toBe('active != true');
+  });
+
+  it('should escape quotes in string values', () => {
+    expect(Expr.eq('name', "O'Brien").toString()).toBe("name == 'O\\'Brien'");
+  });
+
+  it('should combine expressions with and/or/not', () => {
+    const expr = Expr.and(Expr.gt('total', 1000), Expr.or(Expr.eq('region', 'EU'), Expr.eq('region', 'UK')));
+    expect(expr.toString()).toBe("total > 1000 && (region == 'EU' || region == 'UK')");
+    expect(Expr.not(Expr.lt('qty', 5)).toString()).toBe('!(qty < 5)');
+  });
+
+  it('should reject invalid field names', () => {
+    expect(() => Expr.gt('total; drop', 1)).toThrow(ValidationError);
+  });
+});
+
+describe('conditionalVariable', () => {
+  it('should carry a boolean condition', () => {
+    expect(conditionalVariable('{ShowDiscount}', false)).toEqual({
+      placeholder: '{ShowDiscount}',
+      text: 'false',
+      mimeType: 'text',
+    });
+  });
+
+  it('should carry a typed expression', () => {
+    const variable = conditionalVariable('{ShowDiscount}', Expr.gte('total', 1000));
+    expect(variable.text).toBe('total >= 1000');
+  });
+});
+
+describe('VariableSet', () => {
+  const header = VariableSet.from([
+    { placeholder: '{CompanyName}', text: 'TechCorp Inc.', mimeType: 'text' },
+    { placeholder: '{CompanyAddress}', text: '1 Main St', mimeType: 'text' },
+  ]);
+
+  it('should build text variables from a plain object', () => {
+    const set = VariableSet.fromObject({ Total: 6000, Approved: true });
+
+    expect(set.toArray()).toEqual([
+      { placeholder: '{Total}', text: '6000', mimeType: 'text' },
+      { placeholder: '{Approved}', text: 'true', mimeType: 'text' },
+    ]);
+  });
+
+  it('should merge sets with the later set winning on conflicts', () => {
+    const override = VariableSet.from([
+      { placeholder: '{CompanyName}', text: 'TechCorp GmbH', mimeType: 'text' },
+    ]);
+
+    const merged = header.merge(override).toArray();
+
+    expect(merged).toHaveLength(2);
+    expect(merged[0].text).toBe('TechCorp GmbH');
+  });
+
+  it('should prefix placeholder tokens', () => {
+    const prefixed = header.prefix('customer.').toArray();
+
+    expect(prefixed.map((v) => v.placeholder)).toEqual([
+      '{customer.CompanyName}',
+      '{customer.CompanyAddress}',
+    ]);
+  });
+
+  it('should not mutate the original set when prefixing or merging', () => {
+    header.prefix('x.');
+    header.merge(VariableSet.fromObject({ Extra: 1 }));
+
+    expect(header.toArray().map((v) => v.placeholder)).toEqual([
+      '{CompanyName}',
+      '{CompanyAddress}',
+    ]);
+  });
+});
+
+describe('subtemplateVariable', () => {
+  it('should reference the sub-template and its variables', () => {
+    const variable = subtemplateVariable('{SOWSection}', 'sow-template-id', [
+      { placeholder: '{Scope}', text: 'Phase 1 remediation', mimeType: 'text' },
+    ]);
+
+    expect(variable.placeholder).toBe('{SOWSection}');
+    expect(variable.mimeType).toBe('html');
+    expect(variable.templateId).toBe('sow-template-id');
+    expect(variable.subvariables).toHaveLength(1);
+  });
+});
+
+describe('autoVariable', () => {
+  it('should brace a bare token', () => {
+    expect(autoVariable('CustomerName', 'TechCorp Inc.')).toEqual({
+      placeholder: '{CustomerName}',
+      text: 'TechCorp Inc.',
+      mimeType: 'text',
+    });
+  });
+
+  it('should leave an already-braced token unchanged', () => {
+    expect(autoVariable('{Total}', 6000).placeholder).toBe('{Total}');
+  });
+
+  it('should reject empty or malformed tokens', () => {
+    expect(() => autoVariable('', 'x')).toThrow(ValidationError);
+    expect(() => autoVariable('{Cust Name}', 'x')).toThrow(ValidationError);
+    expect(() => autoVariable('{{Nested}}', 'x')).toThrow(ValidationError);
+  });
+});
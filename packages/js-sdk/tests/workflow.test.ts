@@ -0,0 +1,178 @@
+/**
+ * Workflow Module Tests
+ *
+ * Tests for declarative generate-and-send workflow execution: step
+ * ordering, ID recording, spec validation, and file loading.
+ */
+
+import * as fs from 'fs';
+import * as os from 'os';
+import * as path from 'path';
+import { Workflow, WorkflowRunner, loadWorkflowSpec } from '../src/modules/workflow';
+import { Deliverable } from '../src/modules/deliverable';
+import { TurboSign } from '../src/modules/sign';
+import { HttpClient } from '../src/http';
+import { ValidationError } from '../src/utils/errors';
+import type { WorkflowSpec } from '../src/types/workflow';
+
+jest.mock('../src/http');
+
+const MockedHttpClient = HttpClient as jest.MockedClass<typeof HttpClient>;
+
+const spec: WorkflowSpec = {
+  generate: {
+    templateId: 'offer-letter-template',
+    name: 'Offer letter - Jane Smith',
+    variables: [{ placeholder: '{Name}', text: 'Jane Smith', mimeType: 'text' }],
+  },
+  sign: {
+    recipients: [{ name: 'Jane Smith', email: 'jane@example.com', signingOrder: 1 }],
+    fieldLayoutId: 'layout-123',
+  },
+};
+
+const generateResponse = {
+  results: { deliverable: { id: 'del-123', name: 'Offer letter - Jane Smith', templateId: 'offer-letter-template' } },
+};
+const sendResponse = { success: true, documentId: 'doc-456', status: 'sent' };
+
+describe('Workflow Module', () => {
+  beforeEach(() => {
+    jest.clearAllMocks();
+    (TurboSign as any).client = undefined;
+    (TurboSign as any).profiles = new Map();
+    (Deliverable as any).client = undefined;
+
+    MockedHttpClient.prototype.getSenderConfig = jest.fn().mockReturnValue({
+      senderEmail: 'test@company.com',
+      senderName: 'Test Company',
+    });
+    TurboSign.configure({ apiKey: 'test-key', orgId: 'test-org-id', senderEmail: 'test@company.com' });
+    Deliverable.configure({ apiKey: 'test-key', orgId: 'test-org-id' });
+  });
+
+  describe('run', () => {
+    it('should generate, then send the deliverable for signature', async () => {
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValueOnce(generateResponse)
+        .mockResolvedValueOnce(sendResponse);
+
+      const result = await Workflow.run(spec);
+
+      expect(result.deliverableId).toBe('del-123');
+      expect(result.documentId).toBe('doc-456');
+      expect(result.steps).toEqual([
+        { step: 'generate', id: 'del-123' },
+        { step: 'sign', id: 'doc-456' },
+      ]);
+
+      const sendPayload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[1][1];
+      expect(sendPayload.deliverableId).toBe('del-123');
+      expect(sendPayload.fieldLayoutId).toBe('layout-123');
+      // The sign step inherits the deliverable name by default
+      expect(sendPayload.documentName).toBe('Offer letter - Jane Smith');
+    });
+
+    it('should stop after generation when there is no sign step', async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockResolvedValue(generateResponse);
+
+      const result = await Workflow.run({ generate: spec.generate });
+
+      expect(result.deliverableId).toBe('del-123');
+      expect(result.documentId).toBeUndefined();
+      expect(result.steps).toEqual([{ step: 'generate', id: 'del-123' }]);
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledTimes(1);
+    });
+
+    it('should reject a spec without a generate step', async () => {
+      await expect(Workflow.run({} as WorkflowSpec)).rejects.toThrow("missing the 'generate' step");
+    });
+
+    it('should reject a sign step without recipients', async () => {
+      await expect(
+        Workflow.run({ generate: spec.generate, sign: { recipients: [] } })
+      ).rejects.toThrow('at least one recipient');
+    });
+
+    it('should not run the sign step when generation fails', async () => {
+      MockedHttpClient.prototype.post = jest.fn().mockRejectedValue(new Error('template not found'));
+
+      await expect(Workflow.run(spec)).rejects.toThrow('template not found');
+      expect(MockedHttpClient.prototype.post).toHaveBeenCalledTimes(1);
+    });
+  });
+
+  describe('spec files', () => {
+    let specPath: string;
+    let variablesPath: string;
+
+    beforeEach(() => {
+      const dir = os.tmpdir();
+      specPath = path.join(dir, `workflow-spec-${process.pid}-${Date.now()}.json`);
+      variablesPath = path.join(dir, `workflow-vars-${process.pid}-${Date.now()}.json`);
+    });
+
+    afterEach(() => {
+      for (const file of [specPath, variablesPath]) {
+        if (fs.existsSync(file)) {
+          fs.unlinkSync(file);
+        }
+      }
+    });
+
+    it('should run a spec loaded from a JSON file with variables from another file', async () => {
+      fs.writeFileSync(
+        variablesPath,
+        JSON.stringify([{ placeholder: '{Name}', text: 'Jane Smith', mimeType: 'text' }])
+      );
+      fs.writeFileSync(
+        specPath,
+        JSON.stringify({
+          generate: { ...spec.generate, variables: undefined, variablesFile: variablesPath },
+          sign: spec.sign,
+        })
+      );
+      MockedHttpClient.prototype.post = jest
+        .fn()
+        .mockResolvedValueOnce(generateResponse)
+        .mockResolvedValueOnce(sendResponse);
+
+      const result = await Workflow.run(specPath);
+
+      expect(result.steps).toHaveLength(2);
+      const generatePayload = (MockedHttpClient.prototype.post as jest.Mock).mock.calls[0][1];
+      expect(generatePayload.variables).toEqual([
+        { placeholder: '{Name}', text: 'Jane Smith', mimeType: 'text' },
+      ]);
+    });
+
+    it('should fail clearly on a missing spec file', () => {
+      expect(() => loadWorkflowSpec('/nonexistent/workflow.json')).toThrow(ValidationError);
+      expect(() => loadWorkflowSpec('/nonexistent/workflow.json')).toThrow('not found');
+    });
+
+    it('should fail clearly on malformed JSON', () => {
+      fs.writeFileSync(specPath, '{ generate: unquoted }');
+
+      expect(() => loadWorkflowSpec(specPath)).toThrow('not valid JSON');
+    });
+  });
+
+  describe('WorkflowRunner', () => {
+    it('should execute against the clients it was constructed with', async () => {
+      const sign = { sendSignature: jest.fn().mockResolvedValue(sendResponse) };
+      const deliverable = { generateDeliverable: jest.fn().mockResolvedValue(generateResponse) };
+
+      const result = await new WorkflowRunner({ sign, deliverable }).run(spec);
+
+      expect(deliverable.generateDeliverable).toHaveBeenCalledWith(
+        expect.objectContaining({ templateId: 'offer-letter-template' })
+      );
+      expect(sign.sendSignature).toHaveBeenCalledWith(
+        expect.objectContaining({ deliverableId: 'del-123' })
+      );
+      expect(result.documentId).toBe('doc-456');
+    });
+  });
+});